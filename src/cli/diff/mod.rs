@@ -3,13 +3,16 @@ use clap::ArgMatches;
 use tokio::runtime::Runtime;
 use similar::{ChangeTag, TextDiff};
 use termion::color;
+use futures::stream::{self, StreamExt, TryStreamExt};
 use crate::cli::helper::error::{
     CError,
     TypeError,
 };
+use crate::cli::helper::args;
 use crate::cli::helper::logger::{Logger, LogLevel};
 use crate::kube::{
     diff,
+    client::{self, ClusterClient},
     helper::error::KubeError
 };
 
@@ -35,14 +38,19 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
         .ok_or_else(|| CError::from(TypeError::MissingArg(ARG_PATH)))?;
 
     let quiet = args.is_present(ARG_QUIET);
+    let concurrency = args::get_concurrency(args)?;
+    let options = args::get_client_options(args);
     let logger = Logger::new(quiet);
 
-    let generated_yaml = super::generate::generate_yaml_from_toml(path, &logger)?;
+    let generated_yaml = super::generate::generate_yaml_from_toml(path, options.namespace.as_deref(), &logger)?;
 
     // generate a runtime in order to get the dry_run values
     logger.print(LogLevel::Warning("Retrieving existing spec from the cluster..."));
     let rt = Runtime::new()?;
-    let res = rt.block_on(get_existing_spec(generated_yaml.clone(), &logger))
+    let cluster = rt.block_on(client::get_client(&options))
+        .map_err(|err| CError { message: err.message })?;
+
+    let res = rt.block_on(get_existing_spec(&cluster, &generated_yaml, &logger, concurrency))
         .map_err(|err| CError { message: err.message })?;
 
     // compare the spec
@@ -69,19 +77,35 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
 
 /// Get existing spec
 ///
+/// # Description
+/// Retrieve the spec of each template from the cluster. At most `concurrency` templates
+/// are requested at the same time. Results are sorted by template name
+///
 /// # Arguments
-/// * `yaml` - HashMap<String, String>
+/// * `cluster` - &ClusterClient
+/// * `yaml` - &HashMap<String, String>
+/// * `logger` - &Logger
+/// * `concurrency` - usize
 ///
 /// # Return
-/// impl Future<Output = Result<HashMap<String, String>, KubeError>>
-async fn get_existing_spec(yaml: HashMap<String, String>, logger: &Logger) -> Result<HashMap<String, String>, KubeError> {
-    let mut dr = HashMap::new();
-    for (name, content) in yaml {
-        let res = diff::get_current_spec(&content).await?;
-    
-        logger.print(LogLevel::Info(&format!("🪞 Spec retrieved for {}.toml", name)));
-        dr.insert(name.to_owned(), res);
-    }
+/// Result<Vec<(String, String)>, KubeError>
+async fn get_existing_spec(
+    cluster: &ClusterClient,
+    yaml: &HashMap<String, String>,
+    logger: &Logger,
+    concurrency: usize
+) -> Result<Vec<(String, String)>, KubeError> {
+    let mut templates = yaml.iter().collect::<Vec<(&String, &String)>>();
+    templates.sort_by_key(|(name, _)| *name);
+
+    stream::iter(templates)
+        .map(|(name, content)| async move {
+            let res = diff::get_current_spec(cluster, content).await?;
+            logger.print(LogLevel::Info(&format!("🪞 Spec retrieved for {}.toml", name)));
 
-    Ok(dr)
+            Ok((name.to_owned(), res))
+        })
+        .buffered(concurrency)
+        .try_collect::<Vec<(String, String)>>()
+        .await
 }
@@ -1,13 +1,16 @@
 use clap::ArgMatches;
 use tokio::runtime::Runtime;
 use std::collections::HashMap;
+use futures::stream::{self, StreamExt};
 use crate::cli::helper::error::{
     CError,
     TypeError
 };
+use crate::cli::helper::args;
 use crate::cli::helper::logger::{Logger, LogLevel};
 use crate::kube::{
     dry,
+    client::{self, ClusterClient},
     helper::error::KubeError
 };
 
@@ -33,15 +36,18 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
     
     let quiet = args.is_present(ARG_QUIET);
     let unreleased = args.is_present(ARG_UNRELEASED);
+    let concurrency = args::get_concurrency(args)?;
+    let options = args::get_client_options(args);
     let logger = Logger::new(quiet);
 
-    let generated_yaml = super::generate::generate_yaml_from_toml(path, &logger)?;
+    let generated_yaml = super::generate::generate_yaml_from_toml(path, options.namespace.as_deref(), &logger)?;
 
     // spawning a runtime
     let rt = Runtime::new()?;
-    let res = rt.block_on(dry_run_specs(&generated_yaml, unreleased))
+    let cluster = rt.block_on(client::get_client(&options))
         .map_err(|err| CError { message: err.message })?;
 
+    let res = rt.block_on(dry_run_specs(&cluster, &generated_yaml, unreleased, concurrency));
     for (name, dry_res) in res.into_iter() {
         match dry_res {
             Ok(_) => {
@@ -60,25 +66,37 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
 /// Dry Run Spec
 ///
 /// # Description
-/// Dry run the templates with the Kubernetes cluster
+/// Dry run the templates with the Kubernetes cluster. At most `concurrency` templates
+/// are sent to the cluster at the same time. Results are sorted by template name
 ///
 /// # Arguments
+/// * `cluster` - &ClusterClient
 /// * `specs` - &HashMap<String, String>
+/// * `unreleased` - bool
+/// * `concurrency` - usize
 ///
 /// # Return
-/// Result<HashMap<String, Result<String, KubeError>>, KubeError>
-async fn dry_run_specs(specs: &HashMap<String, String>, unreleased: bool) -> Result<HashMap<String, Result<(), KubeError>>, KubeError> {
-    let mut m = HashMap::new();
-    
-    for (name, content) in specs.iter() {
-        if unreleased{
-            let res = dry::dry_run_create(content).await;
-            m.insert(name.to_owned(), res);
-        } else {
-            let res = dry::dry_run(content).await;
-            m.insert(name.to_owned(), res);
-        }
-    }
+/// Vec<(String, Result<(), KubeError>)>
+async fn dry_run_specs(
+    cluster: &ClusterClient,
+    specs: &HashMap<String, String>,
+    unreleased: bool,
+    concurrency: usize
+) -> Vec<(String, Result<(), KubeError>)> {
+    let mut templates = specs.iter().collect::<Vec<(&String, &String)>>();
+    templates.sort_by_key(|(name, _)| *name);
 
-    Ok(m)
-}
\ No newline at end of file
+    stream::iter(templates)
+        .map(|(name, content)| async move {
+            let res = if unreleased {
+                dry::dry_run_create(cluster, content).await
+            } else {
+                dry::dry_run(cluster, content).await
+            };
+
+            (name.to_owned(), res)
+        })
+        .buffered(concurrency)
+        .collect::<Vec<(String, Result<(), KubeError>)>>()
+        .await
+}
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::collections::HashMap;
 use clap::ArgMatches;
 use crate::cli::helper::error::{
@@ -19,6 +20,7 @@ const ARG_PATH: &str = "path";
 const ARG_OUTPUT: &str = "output";
 const ARG_MERGE: &str = "merge";
 const ARG_QUIET: &str = "quiet";
+const NAMESPACE_KIND: &str = "Namespace";
 
 /// Run
 ///
@@ -40,13 +42,8 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
 
     // generate logger based on quiet
     let logger = Logger::new(quiet);
-    let generated_yaml = generate_yaml_from_toml(path, &logger)?;
-    let stitched_yaml = generated_yaml
-        .clone()
-        .into_iter()
-        .map(|(_, v)| v)
-        .collect::<Vec<String>>()
-        .join("");
+    let generated_yaml = generate_yaml_from_toml(path, None, &logger)?;
+    let stitched_yaml = get_ordered_yaml(&generated_yaml).join("");
 
     if let Some(output_path) = output {
         if !merge {
@@ -81,10 +78,12 @@ pub fn run(args: &ArgMatches) -> Result<(), CError> {
 ///
 /// # Arguments
 /// * `path` - &str
+/// * `namespace` - Option<&str> Override the namespace of every namespaced templates
+/// * `logger` - &Logger
 ///
 /// # Return
 /// Result<HashMap<String, String>, CError>
-pub fn generate_yaml_from_toml(path: &str, logger: &Logger) -> Result<HashMap<String, String>, CError> {
+pub fn generate_yaml_from_toml(path: &str, namespace: Option<&str>, logger: &Logger) -> Result<HashMap<String, String>, CError> {
     let (templates, variables) = io::read_files_to_string(path)?;
     let mut generated_yaml = HashMap::new();
 
@@ -96,9 +95,14 @@ pub fn generate_yaml_from_toml(path: &str, logger: &Logger) -> Result<HashMap<St
             .map_err(|err| CError::from(TypeError::Lib(&err.message)))?;
         
         logger.print(LogLevel::Info("⚙️ Parsing template"));
-        let res = parser::get_parsed_objects(updated_templates.as_str())
+        let mut res = parser::get_parsed_objects(updated_templates.as_str())
+            .and_then(|object| object.load_files(Path::new(path)))
             .map_err(|err| CError::from(TypeError::Lib(&err.message)))?;
 
+        if let Some(ns) = namespace.filter(|_| res.kind.is_namespaced()) {
+            res.namespace = Some(ns.to_owned());
+        }
+
         logger.print(LogLevel::Info("✍️ Generate Kubernetes YAML spec"));
         let yaml = kube::generate_yaml(res)
             .map_err(|err| CError::from(TypeError::Lib(&err.message)))?;
@@ -107,4 +111,71 @@ pub fn generate_yaml_from_toml(path: &str, logger: &Logger) -> Result<HashMap<St
     }
 
     Ok(generated_yaml)
+}
+
+/// Get Ordered Yaml
+///
+/// # Description
+/// Order the generated templates by name. Namespaces are placed first so that the merged
+/// output could be applied at once
+///
+/// # Arguments
+/// * `generated_yaml` - &HashMap<String, String>
+///
+/// # Return
+/// Vec<String>
+fn get_ordered_yaml(generated_yaml: &HashMap<String, String>) -> Vec<String> {
+    let mut templates = generated_yaml.iter().collect::<Vec<(&String, &String)>>();
+    templates.sort_by_key(|(name, content)| (!is_namespace(content), *name));
+
+    templates
+        .into_iter()
+        .map(|(_, content)| content.to_owned())
+        .collect()
+}
+
+/// Is Namespace
+///
+/// # Arguments
+/// * `content` - &str
+///
+/// # Return
+/// bool
+fn is_namespace(content: &str) -> bool {
+    serde_yaml::from_str::<serde_yaml::Value>(content)
+        .ok()
+        .and_then(|value| value.get("kind").and_then(|k| k.as_str()).map(|k| k == NAMESPACE_KIND))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_override_namespace_of_namespaced_kinds() {
+        let logger = Logger::new(true);
+        let generated_yaml = generate_yaml_from_toml("examples", Some("override"), &logger).unwrap();
+
+        let deployment = generated_yaml.get("deployment").unwrap();
+        assert!(deployment.contains("namespace: override"));
+
+        let role = generated_yaml.get("role").unwrap();
+        assert!(role.contains("namespace: override"));
+
+        let namespace = generated_yaml.get("namespace").unwrap();
+        assert!(!namespace.contains("namespace: override"));
+
+        let storage_class = generated_yaml.get("storageclass").unwrap();
+        assert!(!storage_class.contains("namespace: override"));
+    }
+
+    #[test]
+    fn expect_to_not_override_namespace_of_custom_kinds() {
+        let logger = Logger::new(true);
+        let generated_yaml = generate_yaml_from_toml("examples/crd", Some("override"), &logger).unwrap();
+
+        let certificate = generated_yaml.get("mcrt").unwrap();
+        assert!(!certificate.contains("namespace: override"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,59 @@
+use clap::ArgMatches;
+use crate::kube::client::ClientOptions;
+use super::error::{
+    CError,
+    TypeError
+};
+
+// Constant
+const ARG_CONCURRENCY: &str = "concurrency";
+const ARG_KUBECONFIG: &str = "kubeconfig";
+const ARG_CONTEXT: &str = "context";
+const ARG_NAMESPACE: &str = "namespace";
+const DEFAULT_CONCURRENCY: usize = 10;
+
+/// Get Concurrency
+///
+/// # Description
+/// Retrieve the number of templates that can be processed concurrently with the cluster
+/// Default to 10 when the argument is not provided
+///
+/// # Arguments
+/// * `args` - &ArgMatches
+///
+/// # Return
+/// Result<usize, CError>
+pub fn get_concurrency(args: &ArgMatches) -> Result<usize, CError> {
+    let concurrency = match args.value_of(ARG_CONCURRENCY) {
+        Some(value) => value.parse::<usize>()
+            .map_err(|_| CError::from(TypeError::InvalidArg(ARG_CONCURRENCY)))?,
+        None => DEFAULT_CONCURRENCY
+    };
+
+    if concurrency == 0 {
+        return Err(CError::from(TypeError::InvalidArg(ARG_CONCURRENCY)));
+    }
+
+    Ok(concurrency)
+}
+
+/// Get Client Options
+///
+/// # Description
+/// Retrieve the options used to target the cluster
+///     - kubeconfig: path of the kubeconfig file
+///     - context: context of the kubeconfig to use
+///     - namespace: namespace which override the `namespace` key of the templates
+///
+/// # Arguments
+/// * `args` - &ArgMatches
+///
+/// # Return
+/// ClientOptions
+pub fn get_client_options(args: &ArgMatches) -> ClientOptions {
+    ClientOptions {
+        kubeconfig: args.value_of(ARG_KUBECONFIG).map(String::from),
+        context: args.value_of(ARG_CONTEXT).map(String::from),
+        namespace: args.value_of(ARG_NAMESPACE).map(String::from)
+    }
+}
@@ -8,6 +8,7 @@ pub enum TypeError<'a> {
     Io(&'a str),
     Lib(&'a str),
     MissingArg(&'a str),
+    InvalidArg(&'a str),
     MissingRes(&'a str)
 }
 
@@ -17,6 +18,7 @@ impl<'a> std::fmt::Display for TypeError<'a> {
             TypeError::Io(msg) => write!(f, "An error occurred during I/O Operation: {}", msg),
             TypeError::Lib(msg) => write!(f, "An error occured with the parser library: {}", msg),
             TypeError::MissingArg(msg) => write!(f, "The argument {} is missing", msg),
+            TypeError::InvalidArg(msg) => write!(f, "The argument {} is invalid", msg),
             TypeError::MissingRes(msg) => write!(f, "Missing result of: {}", msg)
         }
     }
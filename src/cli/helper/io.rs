@@ -6,6 +6,8 @@ use super::error::TypeError;
 
 // Error constant
 const NO_YAML: &str = "Target path is not of type .yaml";
+// Extensions of the files read by read_yaml_files
+const YAML_EXTENSIONS: [&str; 3] = ["yaml", "yml", "json"];
 
 /// Read Files To String
 ///
@@ -92,4 +94,34 @@ pub fn write_multiple_files(path: &str, map: HashMap<String, String>) -> Result<
     }
 
     Ok(())
+}
+
+/// Read Yaml Files
+///
+/// # Description
+/// Read the content of the YAML & JSON files of a folder. Files are sorted by name
+///
+/// # Arguments
+/// * `path` - &str
+///
+/// # Return
+/// Result<Vec<String>, CError>
+pub fn read_yaml_files(path: &str) -> Result<Vec<String>, CError> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(path)? {
+        let path = entry?.path();
+        let is_yaml = path.extension()
+            .and_then(|ext| ext.to_str())
+            .map(|ext| YAML_EXTENSIONS.contains(&ext))
+            .unwrap_or(false);
+
+        if path.is_file() && is_yaml {
+            paths.push(path);
+        }
+    }
+
+    paths.sort();
+    paths.into_iter()
+        .map(|p| fs::read_to_string(p).map_err(CError::from))
+        .collect()
 }
\ No newline at end of file
@@ -9,6 +9,7 @@ pub enum LogLevel<'a> {
     Info(&'a str),
     Warning(&'a str),
     Success(&'a str),
+    Error(&'a str)
 }
 
 impl Logger {
@@ -34,11 +35,10 @@ impl Logger {
     /// # Arguments
     /// * `level` - LogLevel
     pub fn print(&self, level: LogLevel) {
-        if self.quiet {
-           return;
-        }
-
         match level {
+            // errors are displayed even in quiet mode
+            LogLevel::Error(msg) => println!("{}{}", color::Fg(color::Red), msg),
+            _ if self.quiet => {},
             LogLevel::Info(msg) => println!("{}", msg),
             LogLevel::Success(msg) => println!("{}{}", color::Fg(color::LightBlue), msg),
             LogLevel::Warning(msg) => println!("{}{}", color::Fg(color::Yellow), msg)
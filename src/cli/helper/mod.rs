@@ -1,3 +1,4 @@
+pub mod args;
 pub mod error;
 pub mod io;
 pub mod logger;
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod generate;
 pub mod diff;
 pub mod helper;
-pub mod dry;
\ No newline at end of file
+pub mod dry;
+pub mod validate;
\ No newline at end of file
@@ -0,0 +1,104 @@
+use std::fs;
+use clap::ArgMatches;
+use crate::cli::helper::error::{
+    CError,
+    TypeError
+};
+use crate::cli::helper::logger::{Logger, LogLevel};
+use crate::cli::helper::io;
+use crate::kube::validate::{
+    self,
+    schema::OpenApiSchema,
+    crd::CrdSchemas
+};
+
+// Constant
+const ARG_PATH: &str = "path";
+const ARG_QUIET: &str = "quiet";
+const ARG_OFFLINE: &str = "offline";
+const ARG_SCHEMA: &str = "schema";
+const ARG_CRD_DIR: &str = "crd-dir";
+
+/// Run
+///
+/// # Description
+/// Validate the generated YAML templates without a Kubernetes cluster
+///     - Built-in kinds are checked with the k8s_openapi structs
+///     - When a schema file is provided, the templates are checked with the OpenAPI schema
+///     - When a CRD folder is provided, custom templates are checked with the CRD openAPIV3Schema
+///
+/// # Arguments
+/// * `args` - &ArgMatches
+///
+/// # Return
+/// Result<(), CError>
+pub fn run(args: &ArgMatches) -> Result<(), CError> {
+    let path = args.value_of(ARG_PATH)
+        .ok_or_else(|| CError::from(TypeError::MissingArg(ARG_PATH)))?;
+
+    if !args.is_present(ARG_OFFLINE) {
+        return Err(CError {
+            message: "Only the --offline mode is supported. Use the verify command to validate the templates with a cluster".to_owned()
+        });
+    }
+
+    let quiet = args.is_present(ARG_QUIET);
+    let logger = Logger::new(quiet);
+    let schema = match args.value_of(ARG_SCHEMA) {
+        Some(schema_path) => {
+            let content = fs::read_to_string(schema_path)?;
+            let schema = OpenApiSchema::new(&content)
+                .map_err(|err| CError { message: err.message })?;
+
+            Some(schema)
+        },
+        None => None
+    };
+
+    let crds = match args.value_of(ARG_CRD_DIR) {
+        Some(crd_dir) => {
+            let contents = io::read_yaml_files(crd_dir)?;
+            let crds = CrdSchemas::new(&contents)
+                .map_err(|err| CError { message: err.message })?;
+
+            Some(crds)
+        },
+        None => None
+    };
+
+    let generated_yaml = super::generate::generate_yaml_from_toml(path, None, &logger)?;
+    let mut templates = generated_yaml.iter().collect::<Vec<(&String, &String)>>();
+    templates.sort_by_key(|(name, _)| *name);
+
+    let mut invalid = 0;
+    for (name, content) in templates {
+        let report = validate::validate_offline(content, schema.as_ref(), crds.as_ref())
+            .map_err(|err| CError { message: err.message })?;
+
+        for warning in report.warnings.iter() {
+            logger.print(LogLevel::Warning(&format!("⚠️ Template `{}`: {}", name, warning)));
+        }
+
+        if report.errors.is_empty() {
+            if report.warnings.is_empty() {
+                logger.print(LogLevel::Success(&format!("✅ Template `{}` is valid", name)));
+            }
+
+            continue;
+        }
+
+        invalid += 1;
+        logger.print(LogLevel::Error(&format!("❌ Template `{}` is not valid", name)));
+        for err in report.errors {
+            logger.print(LogLevel::Error(&format!("  - {}", err)));
+        }
+    }
+
+    if invalid > 0 {
+        return Err(CError {
+            message: format!("{} template(s) are not valid", invalid)
+        });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,148 @@
+use std::convert::TryFrom;
+use kube::{Client, Config};
+use kube::config::{Kubeconfig, KubeConfigOptions};
+use super::helper::error::KubeError;
+
+/// ClientOptions
+///
+/// # Description
+/// Options used to target a cluster other than the one of the current kubectl context
+#[derive(Debug, Clone, Default)]
+pub struct ClientOptions {
+    pub kubeconfig: Option<String>,
+    pub context: Option<String>,
+    pub namespace: Option<String>
+}
+
+/// ClusterClient
+///
+/// # Description
+/// A Kubernetes client shared by every call made to the cluster. The namespace is the one
+/// used when a template does not specify one
+#[derive(Clone)]
+pub struct ClusterClient {
+    pub client: Client,
+    pub namespace: String
+}
+
+/// ConfigSource
+///
+/// # Description
+/// Source used to load the kube Config
+#[derive(Debug, PartialEq)]
+enum ConfigSource<'a> {
+    Kubeconfig(&'a str),
+    Context,
+    Infer
+}
+
+/// Get Config Source
+///
+/// # Description
+/// Select the source of the kube Config. A kubeconfig path takes precedence over a context which
+/// is read from the default kubeconfig. Otherwise the config is inferred from the environment
+///
+/// # Arguments
+/// * `options` - &ClientOptions
+///
+/// # Return
+/// ConfigSource
+fn get_config_source(options: &ClientOptions) -> ConfigSource<'_> {
+    match (&options.kubeconfig, &options.context) {
+        (Some(path), _) => ConfigSource::Kubeconfig(path),
+        (None, Some(_)) => ConfigSource::Context,
+        (None, None) => ConfigSource::Infer
+    }
+}
+
+/// Get Config
+///
+/// # Description
+/// Load the kube Config. The kubeconfig is either read from the provided path or inferred
+/// from the environment (KUBECONFIG, ~/.kube/config or in-cluster config)
+///
+/// # Arguments
+/// * `options` - &ClientOptions
+///
+/// # Return
+/// Result<Config, KubeError>
+async fn get_config(options: &ClientOptions) -> Result<Config, KubeError> {
+    let kube_options = KubeConfigOptions {
+        context: options.context.to_owned(),
+        ..Default::default()
+    };
+
+    let config = match get_config_source(options) {
+        ConfigSource::Kubeconfig(path) => {
+            let kubeconfig = Kubeconfig::read_from(path)
+                .map_err(|err| KubeError { message: err.to_string() })?;
+
+            Config::from_custom_kubeconfig(kubeconfig, &kube_options).await
+        },
+        ConfigSource::Context => Config::from_kubeconfig(&kube_options).await,
+        ConfigSource::Infer => Config::infer().await
+    };
+
+    config.map_err(|err| KubeError { message: err.to_string() })
+}
+
+/// Get Client
+///
+/// # Description
+/// Create a Kubernetes client. The client is created once and shared by every call made to the cluster
+///
+/// # Arguments
+/// * `options` - &ClientOptions
+///
+/// # Return
+/// Result<ClusterClient, KubeError>
+pub async fn get_client(options: &ClientOptions) -> Result<ClusterClient, KubeError> {
+    let config = get_config(options).await?;
+    let namespace = options.namespace
+        .to_owned()
+        .unwrap_or_else(|| config.default_namespace.to_owned());
+
+    let client = Client::try_from(config)
+        .map_err(|err| KubeError { message: err.to_string() })?;
+
+    Ok(ClusterClient {
+        client,
+        namespace
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_kubeconfig_to_take_precedence() {
+        let options = ClientOptions {
+            kubeconfig: Some("./kubeconfig".to_owned()),
+            context: Some("staging".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(get_config_source(&options), ConfigSource::Kubeconfig("./kubeconfig"));
+    }
+
+    #[test]
+    fn expect_context_to_use_default_kubeconfig() {
+        let options = ClientOptions {
+            context: Some("staging".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(get_config_source(&options), ConfigSource::Context);
+    }
+
+    #[test]
+    fn expect_to_infer_config() {
+        let options = ClientOptions {
+            namespace: Some("rusty".to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(get_config_source(&options), ConfigSource::Infer);
+    }
+}
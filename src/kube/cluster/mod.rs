@@ -0,0 +1,289 @@
+use std::collections::BTreeMap;
+use k8s_openapi::api::core::v1::{
+    Namespace,
+    ResourceQuota,
+    ResourceQuotaSpec,
+    LimitRange,
+    LimitRangeSpec,
+    LimitRangeItem
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use crate::lib::parser::Object;
+use crate::lib::parser::cluster::{Cluster, Limit};
+use crate::lib::parser::workload::resource::Resource;
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+#[derive(Debug, Default)]
+struct ClusterWrapper {
+    namespace: Option<Namespace>,
+    quota: Option<ResourceQuota>,
+    limit_range: Option<LimitRange>
+}
+
+impl ClusterWrapper {
+    fn new() -> Self {
+        ClusterWrapper {
+            ..Default::default()
+        }
+    }
+
+    /// Set Namespace
+    ///
+    /// # Description
+    /// Create a namespace. A namespace is not namespaced thus the namespace of the template is ignored
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn set_namespace(mut self, object: &Object) -> Self {
+        self.namespace = Some(Namespace {
+            metadata: ObjectMeta {
+                namespace: None,
+                ..common::get_metadata_from_object(object)
+            },
+            ..Default::default()
+        });
+
+        self
+    }
+
+    /// Set Quota
+    ///
+    /// # Description
+    /// Create a resource quota with the [quota] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_quota(mut self, object: &Object) -> Result<Self, KubeError> {
+        let quota = get_cluster(object)?
+            .quota
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let mut hard = get_prefixed_quantities(quota.resources.requests, "requests");
+        hard.extend(get_prefixed_quantities(quota.resources.limits, "limits"));
+        hard.extend(quota.count.into_iter().map(|(k, v)| (k, Quantity(v))));
+
+        self.quota = Some(ResourceQuota {
+            metadata: common::get_metadata_from_object(object),
+            spec: Some(ResourceQuotaSpec {
+                hard,
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        Ok(self)
+    }
+
+    /// Set Limit Range
+    ///
+    /// # Description
+    /// Create a limit range with the [limit_range] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_limit_range(mut self, object: &Object) -> Result<Self, KubeError> {
+        let limits = get_cluster(object)?
+            .limits
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        self.limit_range = Some(LimitRange {
+            metadata: common::get_metadata_from_object(object),
+            spec: Some(LimitRangeSpec {
+                limits: limits.into_iter().map(LimitRangeItem::from).collect()
+            })
+        });
+
+        Ok(self)
+    }
+}
+
+impl From<Limit> for LimitRangeItem {
+    fn from(l: Limit) -> Self {
+        LimitRangeItem {
+            type_: l.kind,
+            default: l.default.map(BTreeMap::from).unwrap_or_default(),
+            default_request: l.default_request.map(BTreeMap::from).unwrap_or_default(),
+            max: l.max.map(BTreeMap::from).unwrap_or_default(),
+            min: l.min.map(BTreeMap::from).unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Get Cluster
+///
+/// # Arguments
+/// * `object` - &Object
+///
+/// # Return
+/// Result<Cluster, KubeError>
+fn get_cluster(object: &Object) -> Result<Cluster, KubeError> {
+    let spec = object
+        .spec
+        .to_owned()
+        .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+    match (spec.cluster, spec.error) {
+        (Some(cluster), _) => Ok(cluster),
+        (None, Some(err)) => Err(KubeError::from(err)),
+        (None, None) => Err(KubeError::from(Error::MissingSpec))
+    }
+}
+
+/// Get Prefixed Quantities
+///
+/// # Description
+/// Prefix the resources with the type of quota (i.e: requests.cpu)
+///
+/// # Arguments
+/// * `resource` - Option<Resource>
+/// * `prefix` - &str
+///
+/// # Return
+/// BTreeMap<String, Quantity>
+fn get_prefixed_quantities(resource: Option<Resource>, prefix: &str) -> BTreeMap<String, Quantity> {
+    resource
+        .map(BTreeMap::from)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(k, v)| (format!("{}.{}", prefix, k), v))
+        .collect()
+}
+
+/// Get Cluster From Object
+///
+/// # Description
+/// Generate a namespace, resource quota or limit range
+///
+/// # Arguments
+/// * `object` - Object
+/// * `kind` - String
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_cluster_from_object(object: Object, kind: String) -> Result<String, KubeError> {
+    let cluster_str = match kind.as_str() {
+        "namespace" => {
+            let cluster = ClusterWrapper::new().set_namespace(&object);
+            serde_yaml::to_string(&cluster.namespace)?
+        },
+        "quota" => {
+            let cluster = ClusterWrapper::new().set_quota(&object)?;
+            serde_yaml::to_string(&cluster.quota)?
+        },
+        "limitrange" => {
+            let cluster = ClusterWrapper::new().set_limit_range(&object)?;
+            serde_yaml::to_string(&cluster.limit_range)?
+        },
+        _ => "".to_owned()
+    };
+
+    Ok(cluster_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_create_namespace() {
+        let template = r#"
+        kind = "cluster::namespace"
+        name = "rusty"
+        namespace = "default"
+        metadata = { name = "rusty", team = "rusty" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let namespace = ClusterWrapper::new().set_namespace(&object).namespace.unwrap();
+
+        assert_eq!(namespace.metadata.name.unwrap(), "rusty");
+        assert_eq!(namespace.metadata.labels.get("team").unwrap(), "rusty");
+        assert!(namespace.metadata.namespace.is_none());
+    }
+
+    #[test]
+    fn expect_to_create_quota() {
+        let template = r#"
+        kind = "cluster::quota"
+        name = "rusty-quota"
+        namespace = "rusty"
+        metadata = { name = "rusty-quota" }
+
+        [quota]
+            count = { pods = 10 }
+
+            [quota.requests]
+                cpu = "4"
+                memory = "8Gi"
+
+            [quota.limits]
+                memory = "16Gi"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let quota = ClusterWrapper::new().set_quota(&object).unwrap().quota.unwrap();
+        let hard = quota.spec.unwrap().hard;
+
+        assert_eq!(hard.len(), 4);
+        assert_eq!(hard.get("requests.cpu").unwrap(), &Quantity("4".to_owned()));
+        assert_eq!(hard.get("requests.memory").unwrap(), &Quantity("8Gi".to_owned()));
+        assert_eq!(hard.get("limits.memory").unwrap(), &Quantity("16Gi".to_owned()));
+        assert_eq!(hard.get("pods").unwrap(), &Quantity("10".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_create_limit_range() {
+        let template = r#"
+        kind = "cluster::limitrange"
+        name = "rusty-limits"
+        namespace = "rusty"
+        metadata = { name = "rusty-limits" }
+
+        [limit_range]
+            [limit_range.container]
+                default = { cpu = "500m", memory = "512Mi" }
+                default_request = { cpu = "100m" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let limit_range = ClusterWrapper::new().set_limit_range(&object).unwrap().limit_range.unwrap();
+        let limits = limit_range.spec.unwrap().limits;
+
+        let container = limits.first().unwrap();
+        assert_eq!(container.type_, "Container");
+        assert_eq!(container.default.get("memory").unwrap(), &Quantity("512Mi".to_owned()));
+        assert_eq!(container.default_request.get("cpu").unwrap(), &Quantity("100m".to_owned()));
+        assert!(!container.default_request.contains_key("memory"));
+    }
+
+    #[test]
+    fn expect_to_fail_missing_quota() {
+        let template = r#"
+        kind = "cluster::quota"
+        name = "rusty-quota"
+        metadata = { name = "rusty-quota" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        assert!(get_cluster_from_object(object, "quota".to_owned()).is_err());
+    }
+}
\ No newline at end of file
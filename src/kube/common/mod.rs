@@ -73,7 +73,8 @@ pub fn get_workload_metadata_from_object(object: &Object) -> ObjectMeta {
 /// Get Selector From Object
 ///
 /// # Description
-/// Get the selector. The selector use the same TOML metadata values
+/// Get the selector. The selector use the selector labels of the template which are a subset
+/// of the metadata. The selector labels should not change as the selector of a workload is immutable
 ///
 /// # Arguments
 /// * `object` - &Object
@@ -83,7 +84,7 @@ pub fn get_workload_metadata_from_object(object: &Object) -> ObjectMeta {
 pub fn get_label_selector_from_object(object: &Object) -> LabelSelector {
     LabelSelector {
         match_expressions: Vec::new(),
-        match_labels: object.metadata.to_owned()
+        match_labels: object.selector.to_owned()
     }
 }
 
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
 use toml::Value;
 use serde::Serialize;
@@ -7,11 +8,12 @@ use super::helper::error::KubeError;
 
 #[derive(Debug, Clone, Default, Serialize)]
 struct CustomCrd {
+    #[serde(rename = "apiVersion", skip_serializing_if = "Option::is_none")]
+    api_version: Option<String>,
     kind: String,
     metadata: ObjectMeta,
-    namespace: Option<String>,
-    version: Option<String>,
-    spec: Option<Value>
+    #[serde(flatten)]
+    fields: BTreeMap<String, Value>
 }
 
 impl CustomCrd {
@@ -28,18 +30,17 @@ impl CustomCrd {
     /// Self
     fn new(object: &Object, kind: String) -> Self {
         CustomCrd {
+            api_version: object.version.clone(),
             kind,
             metadata: common::get_metadata_from_object(&object),
-            namespace: object.namespace.to_owned(),
-            version: object.version.clone(),
-            spec: None
+            fields: BTreeMap::new()
         }
     }
 
     /// Set Spec
     ///
     /// # Description
-    /// Set the spec of a CustomCRD
+    /// Set the top-level fields of a CustomCRD (spec, data, rules...)
     ///
     /// # Arguments
     /// * `mut self` - Self
@@ -50,7 +51,7 @@ impl CustomCrd {
     fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
         if let Some(spec) = object.spec.to_owned() {
             if let Some(crd) = spec.crd {
-                self.spec = crd.spec;
+                self.fields = crd.fields;
 
                 return Ok(self);
             }
@@ -160,12 +161,45 @@ mod test {
 
         let crd = res.unwrap();
         assert_eq!(crd.kind, "ManagedCertificate");
-        assert_eq!(crd.version.unwrap(), "networking.gke.io/v1");
+        assert_eq!(crd.api_version.unwrap(), "networking.gke.io/v1");
         assert_eq!(crd.metadata.name.unwrap(), "rusty-certificate");
-        assert_eq!(crd.namespace.unwrap(), "foo");
-        assert!(crd.spec.is_some());
+        assert_eq!(crd.metadata.namespace.unwrap(), "foo");
+        assert!(crd.fields.contains_key("spec"));
 
         let yaml = super::crd_to_yaml(object, "ManagedCertificate".to_owned());
         assert!(yaml.is_ok());
+
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml.unwrap()).unwrap();
+        assert_eq!(value["apiVersion"].as_str().unwrap(), "networking.gke.io/v1");
+        assert_eq!(value["metadata"]["namespace"].as_str().unwrap(), "foo");
+        assert!(value.get("version").is_none());
+        assert!(value.get("namespace").is_none());
+    }
+
+    #[test]
+    fn expect_to_generate_top_level_fields() {
+        let template = r#"
+        kind = "custom::SealedSecret"
+        version = "bitnami.com/v1alpha1"
+        metadata = { name = "rusty-secret" }
+
+        [spec.encryptedData]
+            password = "AgBy8hCi..."
+
+        [spec.template]
+            type = "Opaque"
+
+        [stringData]
+            user = "rusty"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = super::crd_to_yaml(object, "SealedSecret".to_owned()).unwrap();
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+
+        assert_eq!(value["apiVersion"].as_str().unwrap(), "bitnami.com/v1alpha1");
+        assert_eq!(value["kind"].as_str().unwrap(), "SealedSecret");
+        assert_eq!(value["spec"]["template"]["type"].as_str().unwrap(), "Opaque");
+        assert_eq!(value["stringData"]["user"].as_str().unwrap(), "rusty");
     }
 }
\ No newline at end of file
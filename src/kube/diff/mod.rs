@@ -1,10 +1,11 @@
-use kube::{Client, Api};
+use kube::Api;
 use kube::api::DynamicObject;
 use std::collections::BTreeMap;
 use crate::kube::helper::error::{
     KubeError,
     dry_run::Error as KubeRuntimeError
 };
+use super::client::ClusterClient;
 use super::common::{
     Extract,
     get_api_resource,
@@ -12,7 +13,6 @@ use super::common::{
 };
 
 // Constant
-const DEFAULT_NS: &str = "default";
 
 // Annotation constant that need to be remove
 const K8S_REVISION: &str = "deployment.kubernetes.io/revision";
@@ -33,15 +33,12 @@ fn clean_annotations(annotations: &mut BTreeMap<String, String>) {
 /// Retrieve the current spec from an existing resource and transform it in String
 ///
 /// # Arguments
+/// * `cluster` - &ClusterClient
 /// * `content` - &str
 ///
 /// # Return
 /// Result<String, KubeError>
-pub async fn get_current_spec(content: &str) -> Result<String, KubeError> {
-    let client = Client::try_default()
-        .await
-        .map_err(|err| KubeError { message: err.to_string() })?;
-
+pub async fn get_current_spec(cluster: &ClusterClient, content: &str) -> Result<String, KubeError> {
     // parse the generated yaml file
     let extract: Extract = serde_yaml::from_str(content)?;
     
@@ -50,12 +47,12 @@ pub async fn get_current_spec(content: &str) -> Result<String, KubeError> {
     
     // retrieve the name & namespace 
     let ns = extract.metadata.namespace
-        .unwrap_or_else(|| DEFAULT_NS.to_owned());
+        .unwrap_or_else(|| cluster.namespace.to_owned());
 
     let name = extract.metadata.name
         .ok_or_else(|| KubeError::from(KubeRuntimeError::MissingSpecName))?;
         
-    let dynamic: Api<DynamicObject> = Api::namespaced_with(client, &ns, &api_res);
+    let dynamic: Api<DynamicObject> = Api::namespaced_with(cluster.client.clone(), &ns, &api_res);
     let mut res = dynamic.get(&name)
         .await
         .map_err(parse_kube_error)?;
@@ -74,6 +71,8 @@ pub async fn get_current_spec(content: &str) -> Result<String, KubeError> {
 
 #[cfg(test)]
 mod tests {
+    use crate::kube::client::{get_client, ClientOptions};
+
     #[tokio::test]
     async fn expect_to_retrieve_spec() {
         let yaml = r#"
@@ -86,7 +85,8 @@ mod tests {
             name: nginx
         "#;
 
-        let spec = super::get_current_spec(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let spec = super::get_current_spec(&cluster, yaml).await;
         assert!(spec.is_ok());
     }
 
@@ -102,7 +102,8 @@ mod tests {
             name: foo
         "#;
 
-        let spec = super::get_current_spec(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let spec = super::get_current_spec(&cluster, yaml).await;
         assert!(spec.is_err());
     }
 }
\ No newline at end of file
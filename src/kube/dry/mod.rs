@@ -1,6 +1,6 @@
 use serde_json::Value;
 use kube::api::{Api, DynamicObject, Patch, PatchParams, PostParams};
-use kube::Client;
+use super::client::ClusterClient;
 use super::common::{
     Extract,
     get_api_resource,
@@ -13,7 +13,6 @@ use super::helper::error::{
 
 // Constant
 const PATCH_PARAM_MANAGER: &str = "maomao";
-const DEFAULT_NS: &str = "default";
 
 /// Clear Dynamic Object
 ///
@@ -24,13 +23,13 @@ const DEFAULT_NS: &str = "default";
 /// See https://kubernetes.io/docs/reference/using-api/server-side-apply/#clearing-managedfields
 ///
 /// # Arguments
-/// * `client` - Client
+/// * `cluster` - &ClusterClient
 /// * `content` - &str
 /// * `name` - &str
 ///
 /// # Return
 /// Result<(), KubeError>
-async fn clear_dynamic_object(client: Client, content: &str, name: &str) -> Result<(), KubeError> {
+async fn clear_dynamic_object(cluster: &ClusterClient, content: &str, name: &str) -> Result<(), KubeError> {
     let extract: Extract = serde_yaml::from_str(content)?;
     // get the patch params
     let pp = PatchParams::apply(PATCH_PARAM_MANAGER);
@@ -39,7 +38,7 @@ async fn clear_dynamic_object(client: Client, content: &str, name: &str) -> Resu
 
     // get & edit metadata
     let mut metadata = extract.metadata;
-    let ns = metadata.to_owned().namespace.unwrap_or_else(|| DEFAULT_NS.to_owned());
+    let ns = metadata.to_owned().namespace.unwrap_or_else(|| cluster.namespace.to_owned());
     metadata.managed_fields = Vec::new();
     
     // create a Patch that remove the managedField metadata
@@ -50,7 +49,7 @@ async fn clear_dynamic_object(client: Client, content: &str, name: &str) -> Resu
     });
 
     let patch = Patch::Merge(patch_json);
-    let dynamic: Api<DynamicObject> = Api::namespaced_with(client, &ns, &api_res);
+    let dynamic: Api<DynamicObject> = Api::namespaced_with(cluster.client.clone(), &ns, &api_res);
     dynamic.patch(name, &pp, &patch)
         .await
         .map_err(parse_kube_error)?;
@@ -71,20 +70,17 @@ async fn clear_dynamic_object(client: Client, content: &str, name: &str) -> Resu
 /// /!\ Need to wait for a new release of kube-rs following the merge of the PR#512
 ///
 /// # Arguments
+/// * `cluster` - &ClusterClient
 /// * `content` - &str
 ///
 /// # Return
 /// Result<String, KubeError>
-pub async fn dry_run(content: &str) -> Result<(), KubeError> {
-    let client = Client::try_default()
-        .await
-        .map_err(|err| KubeError { message: err.to_string() })?;
-    
+pub async fn dry_run(cluster: &ClusterClient, content: &str) -> Result<(), KubeError> {
     // Extract some values from the yaml
     let extract: Extract = serde_yaml::from_str(content)?;
     // get the namespace from the metadata
     let metadata = extract.metadata.to_owned();
-    let ns = metadata.namespace.unwrap_or_else(|| DEFAULT_NS.to_owned());
+    let ns = metadata.namespace.unwrap_or_else(|| cluster.namespace.to_owned());
 
     let json = serde_yaml::from_str::<Value>(content)?.to_string();
     let patch: Value = serde_json::from_str(&json)?;
@@ -96,7 +92,7 @@ pub async fn dry_run(content: &str) -> Result<(), KubeError> {
 
     // Retrieve the resource from the Cluster as a DynamicObject
     let d: Api<DynamicObject> = Api::namespaced_with(
-        client.clone(), 
+        cluster.client.clone(), 
         &ns, 
         &api_res
     );
@@ -112,7 +108,7 @@ pub async fn dry_run(content: &str) -> Result<(), KubeError> {
     // clear the managed_field in case if it's not already done
     if !res.metadata.managed_fields.is_empty() {
         // clear the dynamic object of it's managedField
-        clear_dynamic_object(client, content, &name).await?;
+        clear_dynamic_object(cluster, content, &name).await?;
     }
     
     Ok(())
@@ -124,22 +120,19 @@ pub async fn dry_run(content: &str) -> Result<(), KubeError> {
 /// Dry Run the TOML template but only used during the creation of the TOML template
 ///
 /// # Arguments
+/// * `cluster` - &ClusterClient
 /// * `content` - &str
 ///
 /// # Return
 /// Result<(), KubeError> 
-pub async fn dry_run_create(content: &str) -> Result<(), KubeError> {
-    let client = Client::try_default()
-        .await
-        .map_err(|err| KubeError { message: err.to_string() })?;
-
+pub async fn dry_run_create(cluster: &ClusterClient, content: &str) -> Result<(), KubeError> {
     // Extract some values from the yaml
     let extract: Extract = serde_yaml::from_str(content)?;
     let api_res = get_api_resource(&extract)?;
     let ns = extract.metadata.namespace
-        .unwrap_or_else(|| DEFAULT_NS.to_owned());
+        .unwrap_or_else(|| cluster.namespace.to_owned());
 
-    let d: Api<DynamicObject> = Api::namespaced_with(client, &ns, &api_res);
+    let d: Api<DynamicObject> = Api::namespaced_with(cluster.client.clone(), &ns, &api_res);
     let pp = PostParams { dry_run: true, ..Default::default() };
     let value: DynamicObject = serde_yaml::from_str(content)?;
 
@@ -153,6 +146,8 @@ pub async fn dry_run_create(content: &str) -> Result<(), KubeError> {
 // These tests need at least the deployment.toml from the examples folder to be deploy
 #[cfg(test)]
 mod tests {
+    use crate::kube::client::{get_client, ClientOptions};
+
     #[tokio::test]
     async fn expect_to_run_dry_run() {
         let yaml = r#"     
@@ -167,7 +162,8 @@ mod tests {
           replicas: 5
         "#;
 
-        let res = super::dry_run(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run(&cluster, yaml).await;
         assert!(res.is_ok());
     }
 
@@ -185,7 +181,8 @@ mod tests {
           replicas: foo
         "#;
 
-        let res = super::dry_run(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run(&cluster, yaml).await;
         assert!(res.is_err());
     }
 
@@ -207,7 +204,8 @@ mod tests {
                   port: 90
         "#;
 
-        let res = super::dry_run(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run(&cluster, yaml).await;
         println!("{:?}", res);
         assert!(res.is_ok());
     }
@@ -227,7 +225,8 @@ mod tests {
                   port: 80
         "#;
 
-        let res = super::dry_run(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run(&cluster, yaml).await;
         assert!(res.is_err());
         let msg = res.unwrap_err();
 
@@ -262,7 +261,8 @@ mod tests {
                           imagePullPolicy: Always   
         "#;
 
-        let res = super::dry_run_create(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run_create(&cluster, yaml).await;
         println!("{:?}", res);
         assert!(res.is_ok());
     }
@@ -285,7 +285,8 @@ mod tests {
                   imagePullPolicy: Foo   
         "#;
 
-        let res = super::dry_run_create(yaml).await;
+        let cluster = get_client(&ClientOptions::default()).await.unwrap();
+        let res = super::dry_run_create(&cluster, yaml).await;
         assert!(res.is_err());
     }
 }
\ No newline at end of file
@@ -59,6 +59,10 @@ impl EnvWrapper {
             }
         }
 
+        configmap.binary_data.extend(env.binary_data
+            .into_iter()
+            .map(|(k, v)| (k, ByteString(v))));
+
         self.configmap = Some(configmap);
 
         Ok(self)
@@ -98,6 +102,10 @@ impl EnvWrapper {
                 .collect();
         }
 
+        secret.data.extend(env.binary_data
+            .into_iter()
+            .map(|(k, v)| (k, ByteString(v))));
+
         self.secret = Some(secret);
 
         Ok(self)
@@ -255,4 +263,24 @@ mod tests {
         let res = get_env_from_object(object, "secret".to_owned());
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn expect_to_create_configmap_from_files() {
+        let template = r#"
+        kind = "env::map"
+        name = "nginx-configmap"
+        metadata = { name = "nginx-configmap", tier = "backend" }
+
+        env_file = "files/nginx.env"
+        files = { "nginx.conf" = "files/nginx.conf" }
+        "#;
+
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let object = get_parsed_objects(template).unwrap().load_files(&dir).unwrap();
+        let configmap = EnvWrapper::new().set_configmap(&object).unwrap().configmap.unwrap();
+
+        assert!(configmap.data.get("nginx.conf").unwrap().contains("server_name foo.bar.com"));
+        assert_eq!(configmap.data.get("LOG_LEVEL").unwrap(), "warn");
+        assert!(configmap.binary_data.is_empty());
+    }
 }
\ No newline at end of file
@@ -71,18 +71,26 @@ impl From<dry_run::Error> for KubeError {
     }
 }
 
+impl From<validate::Error> for KubeError {
+    fn from(err: validate::Error) -> Self {
+        KubeError { message: err.to_string() }
+    }
+}
+
 pub mod common {
     use std::fmt;
     
     #[derive(Debug)]
     pub enum Error {
-        MissingSpec
+        MissingSpec,
+        MissingTargetName
     }
 
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             match self {
-                Error::MissingSpec => write!(f, "Spec is missing from parser object body") 
+                Error::MissingSpec => write!(f, "Spec is missing from parser object body"),
+                Error::MissingTargetName => write!(f, "Unable to find the name of the autoscaling target. Set the target name, the `name` label or the name of the template")
             }
         }
     }
@@ -105,4 +113,31 @@ pub mod dry_run {
             }
         }
     }
+}
+
+pub mod validate {
+    use std::fmt;
+
+    #[derive(Debug)]
+    pub enum Error {
+        MissingDefinitions,
+        SchemaNotFound(String),
+        MissingRequired(String),
+        UnknownField,
+        WrongType(String, String),
+        UnsupportedValue(String, String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::MissingDefinitions => write!(f, "`definitions` could not be founded in the OpenAPI schema"),
+                Error::SchemaNotFound(kind) => write!(f, "No schema found for the kind {}. The template has not been validated", kind),
+                Error::MissingRequired(field) => write!(f, "missing required field `{}`", field),
+                Error::UnknownField => write!(f, "unknown field"),
+                Error::WrongType(expected, found) => write!(f, "expected type `{}`, found `{}`", expected, found),
+                Error::UnsupportedValue(value, allowed) => write!(f, "unsupported value `{}`, expected one of: {}", value, allowed)
+            }
+        }
+    }
 }
\ No newline at end of file
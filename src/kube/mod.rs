@@ -2,11 +2,18 @@ pub mod workload;
 pub mod dry;
 pub mod diff;
 pub mod helper;
+pub mod client;
+pub mod validate;
 
 mod network;
 mod common;
 mod crd;
 mod env;
+mod scaling;
+mod policy;
+mod rbac;
+mod cluster;
+mod storage;
 
 use crate::lib::parser::{Object, Kind};
 
@@ -23,6 +30,11 @@ pub fn generate_yaml(object: Object) -> Result<String, helper::error::KubeError>
         Kind::Network(kind) => network::parse_network_from_object(object, kind)?,
         Kind::Env(kind) => env::get_env_from_object(object, kind)?,
         Kind::Custom(kind) => crd::crd_to_yaml(object, kind)?,
+        Kind::Scaling(kind) => scaling::get_scaling_from_object(object, kind)?,
+        Kind::Policy(kind) => policy::get_policy_from_object(object, kind)?,
+        Kind::Rbac(kind) => rbac::get_rbac_from_object(object, kind)?,
+        Kind::Cluster(kind) => cluster::get_cluster_from_object(object, kind)?,
+        Kind::Storage(kind) => storage::get_storage_from_object(object, kind)?,
         _ => String::new()
     };
 
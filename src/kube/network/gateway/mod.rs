@@ -0,0 +1,165 @@
+use serde::{Serialize, Deserialize};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use crate::lib::parser::Object;
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+mod spec;
+
+// Constant
+const GATEWAY_API_VERSION: &str = "gateway.networking.k8s.io/v1";
+const GATEWAY_KIND: &str = "Gateway";
+
+/// Gateway
+///
+/// # Description
+/// gateway.networking.k8s.io/v1 Gateway. The Gateway API isn't provided by k8s_openapi
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Gateway {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<spec::GatewaySpec>
+}
+
+struct GatewayWrapper {
+    gateway: Gateway
+}
+
+impl GatewayWrapper {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Gateway object
+    ///
+    /// # Arguments
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn new(object: &Object) -> Self {
+        let gateway = Gateway {
+            api_version: GATEWAY_API_VERSION.to_owned(),
+            kind: GATEWAY_KIND.to_owned(),
+            metadata: common::get_metadata_from_object(object),
+            spec: None
+        };
+
+        GatewayWrapper { gateway }
+    }
+
+    /// Set Spec
+    ///
+    /// # Description
+    /// Set the spec of the Gateway
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
+        let spec = object
+            .spec
+            .to_owned()
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let gateway = match (spec.network.and_then(|n| n.gateway), spec.error) {
+            (Some(gateway), _) => gateway,
+            (None, Some(err)) => return Err(KubeError::from(err)),
+            (None, None) => return Err(KubeError::from(Error::MissingSpec))
+        };
+
+        self.gateway.spec = Some(spec::get_gateway_spec(gateway));
+
+        Ok(self)
+    }
+}
+
+/// Get Gateway From Object
+///
+/// # Description
+/// Generate a Gateway resource from a Parser Object
+///
+/// # Arguments
+/// * `object` - Object
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_gateway_from_object(object: Object) -> Result<String, KubeError> {
+    let gateway = GatewayWrapper::new(&object).set_spec(&object)?;
+    let gateway_string = serde_yaml::to_string(&gateway.gateway)?;
+
+    Ok(gateway_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_create_gateway() {
+        let template = r#"
+        kind = 'network::gateway'
+        name = 'rusty'
+        metadata = { name = 'rusty' }
+
+        [gateway]
+            class = 'istio'
+
+            [gateway.listeners]
+                [gateway.listeners.https]
+                    protocol = 'HTTPS'
+                    hostname = '*.foo.bar.com'
+                    tls = 'foo-ssl-certificates'
+                    allowed_routes = 'All'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let gateway = GatewayWrapper::new(&object).set_spec(&object).unwrap().gateway;
+        assert_eq!(gateway.api_version, "gateway.networking.k8s.io/v1");
+        assert_eq!(gateway.kind, "Gateway");
+
+        let spec = gateway.spec.unwrap();
+        assert_eq!(spec.gateway_class_name, "istio");
+
+        let listener = spec.listeners.first().unwrap();
+        assert_eq!(listener.name, "https");
+        assert_eq!(listener.port, 443);
+
+        let tls = listener.tls.as_ref().unwrap();
+        assert_eq!(tls.mode, "Terminate");
+        assert_eq!(tls.certificate_refs.first().unwrap().name, "foo-ssl-certificates");
+        assert_eq!(listener.allowed_routes.as_ref().unwrap().namespaces.from, "All");
+    }
+
+    #[test]
+    fn expect_to_generate_gateway_yaml() {
+        let template = r#"
+        kind = 'network::gateway'
+        name = 'rusty'
+        metadata = { name = 'rusty' }
+
+        [gateway]
+            class = 'istio'
+
+            [gateway.listeners]
+                [gateway.listeners.http]
+                    port = 8080
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = get_gateway_from_object(object).unwrap();
+
+        assert!(yaml.contains("apiVersion: gateway.networking.k8s.io/v1"));
+        assert!(yaml.contains("gatewayClassName: istio"));
+        assert!(!yaml.contains("allowedRoutes"));
+    }
+}
\ No newline at end of file
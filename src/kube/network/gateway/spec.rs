@@ -0,0 +1,95 @@
+use std::convert::From;
+use serde::{Serialize, Deserialize};
+use crate::lib::parser::network::gateway::{
+    Gateway,
+    Listener as ParserListener
+};
+
+// Constant
+const TLS_MODE: &str = "Terminate";
+
+/// GatewaySpec
+///
+/// # Description
+/// Spec of a gateway.networking.k8s.io/v1 Gateway
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewaySpec {
+    pub gateway_class_name: String,
+    pub listeners: Vec<Listener>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Listener {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    pub port: i32,
+    pub protocol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tls: Option<GatewayTLSConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_routes: Option<AllowedRoutes>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GatewayTLSConfig {
+    pub mode: String,
+    pub certificate_refs: Vec<SecretObjectReference>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SecretObjectReference {
+    pub name: String
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AllowedRoutes {
+    pub namespaces: RouteNamespaces
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RouteNamespaces {
+    pub from: String
+}
+
+impl From<ParserListener> for Listener {
+    fn from(l: ParserListener) -> Self {
+        let tls = l.tls.map(|secret| GatewayTLSConfig {
+            mode: TLS_MODE.to_owned(),
+            certificate_refs: vec![SecretObjectReference { name: secret }]
+        });
+
+        let allowed_routes = l.allowed_routes.map(|from| AllowedRoutes {
+            namespaces: RouteNamespaces { from }
+        });
+
+        Listener {
+            name: l.name,
+            hostname: l.hostname,
+            port: l.port,
+            protocol: l.protocol,
+            tls,
+            allowed_routes
+        }
+    }
+}
+
+/// Get Gateway Spec
+///
+/// # Arguments
+/// * `gateway` - Gateway
+///
+/// # Return
+/// GatewaySpec
+pub fn get_gateway_spec(gateway: Gateway) -> GatewaySpec {
+    GatewaySpec {
+        gateway_class_name: gateway.class,
+        listeners: gateway.listeners
+            .into_iter()
+            .map(Listener::from)
+            .collect::<Vec<Listener>>()
+    }
+}
\ No newline at end of file
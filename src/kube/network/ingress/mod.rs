@@ -7,6 +7,7 @@ use crate::kube::helper::error::{
 };
 
 mod spec;
+mod preset;
 
 struct IngressWrapper {
     ingress: Ingress
@@ -55,6 +56,10 @@ impl IngressWrapper {
         }
 
         if let Some(network) = s.network {
+            if let Some(preset) = network.ingress.as_ref().and_then(|ig| ig.preset.as_ref()) {
+                self.ingress.metadata.annotations.extend(preset::get_preset_annotations(preset));
+            }
+
             let spec = spec::get_ingress_spec(network.ingress);
             self.ingress.spec = spec;
         }
@@ -185,4 +190,59 @@ mod tests {
         let ingress = get_ingress_from_object(object);
         assert!(ingress.is_ok());
     }
+
+    #[test]
+    fn expect_to_set_class_tls_and_backends() {
+        let template = r"
+        kind = 'network::ingress'
+        name = 'rusty'
+        metadata = { name = 'rusty', tier = 'ingress' }
+
+        [ingress]
+            preset = { controller = 'nginx', rewrite_target = '/', ssl_redirect = true }
+
+            [ingress.default]
+                backend = { resource = { api_group = 'k8s.example.com', kind = 'StorageBucket', name = 'static-assets' } }
+
+            [ingress.rules]
+                [ingress.rules.rusty]
+                    host = 'foo.bar.com'
+                    tls = 'foo-ssl-certificates'
+
+                    [ingress.rules.rusty.paths]
+                        [ingress.rules.rusty.paths.0]
+                            path = '/'
+                            backend = { name = 'rusty', port = 'http' }
+
+            [ingress.tls]
+                [ingress.tls.capoo]
+                    hosts = ['capoo.com']
+                    secrets = 'capoo-ssl-certificates'
+        ";
+
+        let object = get_parsed_objects(template).unwrap();
+        let ingress = IngressWrapper::new(&object).set_spec(&object).unwrap().ingress;
+
+        let annotations = ingress.metadata.annotations;
+        assert_eq!(annotations.get("nginx.ingress.kubernetes.io/rewrite-target").unwrap(), "/");
+        assert_eq!(annotations.get("nginx.ingress.kubernetes.io/ssl-redirect").unwrap(), "true");
+
+        let spec = ingress.spec.unwrap();
+        assert_eq!(spec.ingress_class_name.unwrap(), "nginx");
+
+        let resource = spec.default_backend.unwrap().resource.unwrap();
+        assert_eq!(resource.kind, "StorageBucket");
+
+        assert_eq!(spec.tls.len(), 2);
+        let rule_tls = spec.tls.last().unwrap();
+        assert_eq!(rule_tls.hosts, vec!["foo.bar.com"]);
+        assert_eq!(rule_tls.secret_name.as_ref().unwrap(), "foo-ssl-certificates");
+
+        let path = spec.rules.first().unwrap().http.to_owned().unwrap().paths.first().unwrap().to_owned();
+        assert_eq!(path.path_type.unwrap(), "Prefix");
+
+        let port = path.backend.service.unwrap().port.unwrap();
+        assert_eq!(port.name.unwrap(), "http");
+        assert!(port.number.is_none());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+use crate::lib::parser::network::ingress::{
+    Preset,
+    Controller
+};
+
+// Constant
+const NGINX_REWRITE_TARGET: &str = "nginx.ingress.kubernetes.io/rewrite-target";
+const NGINX_SSL_REDIRECT: &str = "nginx.ingress.kubernetes.io/ssl-redirect";
+const TRAEFIK_ENTRYPOINTS: &str = "traefik.ingress.kubernetes.io/router.entrypoints";
+const TRAEFIK_TLS: &str = "traefik.ingress.kubernetes.io/router.tls";
+
+/// Get Preset Annotations
+///
+/// # Description
+/// Generate the annotations of the ingress controller from the preset.
+/// With traefik the ssl redirect only route the ingress through the websecure entrypoint
+///
+/// # Arguments
+/// * `preset` - &Preset
+///
+/// # Return
+/// BTreeMap<String, String>
+pub fn get_preset_annotations(preset: &Preset) -> BTreeMap<String, String> {
+    let mut annotations = BTreeMap::new();
+
+    match preset.controller {
+        Controller::Nginx => {
+            if let Some(target) = preset.rewrite_target.to_owned() {
+                annotations.insert(NGINX_REWRITE_TARGET.to_owned(), target);
+            }
+
+            if let Some(redirect) = preset.ssl_redirect {
+                annotations.insert(NGINX_SSL_REDIRECT.to_owned(), redirect.to_string());
+            }
+        },
+        Controller::Traefik => {
+            if let Some(true) = preset.ssl_redirect {
+                annotations.insert(TRAEFIK_ENTRYPOINTS.to_owned(), "websecure".to_owned());
+                annotations.insert(TRAEFIK_TLS.to_owned(), "true".to_owned());
+            }
+        }
+    }
+
+    annotations
+}
\ No newline at end of file
@@ -9,11 +9,13 @@ use k8s_openapi::api::networking::v1::{
     IngressRule,
     IngressTLS
 };
+use k8s_openapi::api::core::v1::TypedLocalObjectReference;
 use crate::lib::parser::network::ingress::{
     Ingress,
     IngressHTTPPath,
 };
 use crate::lib::parser::network::backend::Backend;
+use crate::lib::parser::network::policy::Port;
 
 /// Get Ingress Spec
 ///
@@ -31,9 +33,10 @@ pub fn get_ingress_spec(ingress: Option<Ingress>) -> Option<IngressSpec> {
         let tls = get_tls_rules(&ig);
 
         return Some(IngressSpec {
+            default_backend: ig.default.map(IngressBackend::from),
+            ingress_class_name: ig.class,
             rules,
-            tls,
-            ..Default::default()
+            tls
         });
     }
 
@@ -70,7 +73,8 @@ fn get_ingress_rules(ingress: &Ingress) -> Vec<IngressRule> {
 /// Get Tls Rules
 ///
 /// # Description
-/// Retrieve the tls rules from the parser ingress struct
+/// Retrieve the tls rules from the parser ingress struct. A rule which specify a tls secret
+/// create a tls block for the host of the rule
 ///
 /// # Arguments
 /// * `ingress` - &Ingress
@@ -78,16 +82,29 @@ fn get_ingress_rules(ingress: &Ingress) -> Vec<IngressRule> {
 /// # Return
 /// Option<Vec<IngressTLS>>
 fn get_tls_rules(ingress: &Ingress) -> Vec<IngressTLS> {
-    if let Some(t) = ingress.tls.to_owned() {
-        let tls = IngressTLS {
+    let mut tls = ingress.tls
+        .to_owned()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|t| IngressTLS {
             hosts: t.hosts.unwrap_or_default(),
             secret_name: t.secrets
-        };
-        
-        return vec![tls];
+        })
+        .collect::<Vec<IngressTLS>>();
+
+    if let Some(rules) = ingress.rules.to_owned() {
+        let rules_tls = rules
+            .into_iter()
+            .filter(|rule| rule.tls.is_some())
+            .map(|rule| IngressTLS {
+                hosts: if rule.host.is_empty() { Vec::new() } else { vec![rule.host] },
+                secret_name: rule.tls
+            });
+
+        tls.extend(rules_tls);
     }
 
-    Vec::new()
+    tls
 }
 
 /// Get HTTP Ingress Paths
@@ -122,12 +139,25 @@ impl From<IngressHTTPPath> for HTTPIngressPath {
 
 impl From<Backend> for IngressBackend {
     fn from(backend: Backend) -> Self {
+        if let Some(resource) = backend.resource {
+            return IngressBackend {
+                service: None,
+                resource: Some(TypedLocalObjectReference {
+                    api_group: resource.api_group,
+                    kind: resource.kind,
+                    name: resource.name
+                })
+            };
+        }
+
+        let port = match backend.port {
+            Port::Number(number) => ServiceBackendPort { name: None, number: Some(number) },
+            Port::Name(name) => ServiceBackendPort { name: Some(name), number: None }
+        };
+
         let service_backend = IngressServiceBackend {
             name: backend.name,
-            port: Some(ServiceBackendPort {
-                name: None,
-                number: Some(backend.port as i32),
-            })
+            port: Some(port)
         };
 
         IngressBackend {
@@ -3,6 +3,9 @@ use super::helper::error::KubeError;
 
 mod services;
 mod ingress;
+mod policy;
+mod gateway;
+mod route;
 
 /// Parse Network From Object
 ///
@@ -19,6 +22,9 @@ pub fn parse_network_from_object(object: Object, kind: String) -> Result<String,
     let res = match kind.as_str() {
         "service" => services::get_service_from_object(object),
         "ingress" => ingress::get_ingress_from_object(object),
+        "policy" => policy::get_network_policy_from_object(object),
+        "gateway" => gateway::get_gateway_from_object(object),
+        "httproute" => route::get_http_route_from_object(object),
         // @TODO replace by something else
         _ => Ok("".to_owned())
     };
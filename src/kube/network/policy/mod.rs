@@ -0,0 +1,164 @@
+use k8s_openapi::api::networking::v1::NetworkPolicy;
+use crate::lib::parser::Object;
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+mod spec;
+
+struct NetworkPolicyWrapper {
+    policy: NetworkPolicy
+}
+
+impl NetworkPolicyWrapper {
+    /// New
+    ///
+    /// # Description
+    /// Create a new network policy by first setting up the metadata
+    ///
+    /// # Arguments
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn new(object: &Object) -> Self {
+        let policy = NetworkPolicy {
+            metadata: common::get_metadata_from_object(object),
+            ..Default::default()
+        };
+
+        NetworkPolicyWrapper {
+            policy
+        }
+    }
+
+    /// Set Spec
+    ///
+    /// # Description
+    /// Set the spec of a NetworkPolicy resource
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
+        let spec = object
+            .spec
+            .to_owned()
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let policy = match (spec.network.and_then(|n| n.policy), spec.error) {
+            (Some(policy), _) => policy,
+            (None, Some(err)) => return Err(KubeError::from(err)),
+            (None, None) => return Err(KubeError::from(Error::MissingSpec))
+        };
+
+        self.policy.spec = Some(spec::get_network_policy_spec(policy, object));
+
+        Ok(self)
+    }
+}
+
+/// Get Network Policy From Object
+///
+/// # Description
+/// Generate a NetworkPolicy resource from a Parser Object
+///
+/// # Arguments
+/// * `object` - Object
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_network_policy_from_object(object: Object) -> Result<String, KubeError> {
+    let policy = NetworkPolicyWrapper::new(&object).set_spec(&object)?;
+    let policy_string = serde_yaml::to_string(&policy.policy)?;
+
+    Ok(policy_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lib::parser::get_parsed_objects;
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+
+    #[test]
+    fn expect_to_create_network_policy() {
+        let template = r#"
+            kind = 'network::policy'
+            name = 'rusty'
+            namespace = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [policy]
+                [policy.ingress.frontend]
+                    from = [
+                        { namespace_selector = { team = 'frontend' }, pod_selector = { app = 'web' } },
+                        { ip_block = { cidr = '10.0.0.0/8', except = ['10.0.1.0/24'] } }
+                    ]
+                    ports = [
+                        { protocol = 'TCP', port = 8080 }
+                    ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let policy = NetworkPolicyWrapper::new(&object).set_spec(&object).unwrap().policy;
+        assert_eq!(policy.metadata.namespace.unwrap(), "rusty");
+
+        let spec = policy.spec.unwrap();
+        assert_eq!(spec.pod_selector.match_labels.get("name").unwrap(), "rusty");
+        assert_eq!(spec.policy_types, vec!["Ingress"]);
+
+        let rule = spec.ingress.first().unwrap();
+        let peer = rule.from.first().unwrap();
+        assert_eq!(peer.namespace_selector.as_ref().unwrap().match_labels.get("team").unwrap(), "frontend");
+        assert_eq!(peer.pod_selector.as_ref().unwrap().match_labels.get("app").unwrap(), "web");
+
+        let block = rule.from.last().unwrap().ip_block.as_ref().unwrap();
+        assert_eq!(block.cidr, "10.0.0.0/8");
+        assert_eq!(block.except, vec!["10.0.1.0/24"]);
+
+        let port = rule.ports.first().unwrap();
+        assert_eq!(port.port.as_ref().unwrap(), &IntOrString::Int(8080));
+        assert_eq!(port.protocol.as_ref().unwrap(), "TCP");
+    }
+
+    #[test]
+    fn expect_to_create_default_deny() {
+        let template = r#"
+            kind = 'network::policy'
+            name = 'default-deny'
+            namespace = 'rusty'
+            metadata = { name = 'default-deny' }
+
+            [policy]
+                default_deny = 'all'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_network_policy_from_object(object).unwrap();
+
+        assert!(res.contains("podSelector: {}"));
+        assert!(res.contains("- Ingress"));
+        assert!(res.contains("- Egress"));
+        assert!(!res.contains("ingress:"));
+    }
+
+    #[test]
+    fn expect_to_fail_missing_policy() {
+        let template = r#"
+            kind = 'network::policy'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_network_policy_from_object(object);
+
+        assert!(res.is_err());
+    }
+}
\ No newline at end of file
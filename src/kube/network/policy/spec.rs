@@ -0,0 +1,125 @@
+use std::convert::From;
+use std::collections::BTreeMap;
+use k8s_openapi::api::networking::v1::{
+    NetworkPolicySpec,
+    NetworkPolicyIngressRule,
+    NetworkPolicyEgressRule,
+    NetworkPolicyPeer,
+    NetworkPolicyPort,
+    IPBlock
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::LabelSelector;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use crate::lib::parser::Object;
+use crate::lib::parser::network::policy::{
+    NetworkPolicy,
+    Peer,
+    PolicyPort,
+    Port
+};
+use crate::kube::common;
+
+// Constant
+const INGRESS: &str = "Ingress";
+const EGRESS: &str = "Egress";
+
+/// Get Network Policy Spec
+///
+/// # Description
+/// Retrieve the NetworkPolicySpec. The pods are selected with
+///     - The pod_selector when it's specified
+///     - Every pods of the namespace when default_deny is used
+///     - The metadata of the template otherwise
+///
+/// # Arguments
+/// * `policy` - NetworkPolicy
+/// * `object` - &Object
+///
+/// # Return
+/// NetworkPolicySpec
+pub fn get_network_policy_spec(policy: NetworkPolicy, object: &Object) -> NetworkPolicySpec {
+    let pod_selector = match policy.pod_selector {
+        Some(labels) => get_label_selector(labels),
+        None if !policy.default_deny.is_empty() => LabelSelector::default(),
+        None => common::get_label_selector_from_object(object)
+    };
+
+    let ingress = policy.ingress
+        .unwrap_or_default()
+        .into_iter()
+        .map(|rule| NetworkPolicyIngressRule {
+            from: rule.peers.into_iter().map(NetworkPolicyPeer::from).collect(),
+            ports: rule.ports.into_iter().map(NetworkPolicyPort::from).collect()
+        })
+        .collect::<Vec<NetworkPolicyIngressRule>>();
+
+    let egress = policy.egress
+        .unwrap_or_default()
+        .into_iter()
+        .map(|rule| NetworkPolicyEgressRule {
+            to: rule.peers.into_iter().map(NetworkPolicyPeer::from).collect(),
+            ports: rule.ports.into_iter().map(NetworkPolicyPort::from).collect()
+        })
+        .collect::<Vec<NetworkPolicyEgressRule>>();
+
+    let mut policy_types = Vec::new();
+    if !ingress.is_empty() || policy.default_deny.iter().any(|d| d == INGRESS) {
+        policy_types.push(INGRESS.to_owned());
+    }
+
+    if !egress.is_empty() || policy.default_deny.iter().any(|d| d == EGRESS) {
+        policy_types.push(EGRESS.to_owned());
+    }
+
+    NetworkPolicySpec {
+        pod_selector,
+        ingress,
+        egress,
+        policy_types
+    }
+}
+
+/// Get Label Selector
+///
+/// # Arguments
+/// * `labels` - BTreeMap<String, String>
+///
+/// # Return
+/// LabelSelector
+fn get_label_selector(labels: BTreeMap<String, String>) -> LabelSelector {
+    LabelSelector {
+        match_labels: labels,
+        ..Default::default()
+    }
+}
+
+impl From<Peer> for NetworkPolicyPeer {
+    fn from(p: Peer) -> Self {
+        NetworkPolicyPeer {
+            ip_block: p.ip_block.map(|b| IPBlock {
+                cidr: b.cidr,
+                except: b.except
+            }),
+            namespace_selector: p.namespace_selector.map(get_label_selector),
+            pod_selector: p.pod_selector.map(get_label_selector)
+        }
+    }
+}
+
+impl From<PolicyPort> for NetworkPolicyPort {
+    fn from(p: PolicyPort) -> Self {
+        NetworkPolicyPort {
+            port: p.port.map(IntOrString::from),
+            protocol: p.protocol
+        }
+    }
+}
+
+impl From<Port> for IntOrString {
+    fn from(p: Port) -> Self {
+        match p {
+            Port::Number(number) => IntOrString::Int(number),
+            Port::Name(name) => IntOrString::String(name)
+        }
+    }
+}
\ No newline at end of file
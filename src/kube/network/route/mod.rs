@@ -0,0 +1,183 @@
+use serde::{Serialize, Deserialize};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use crate::lib::parser::Object;
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+mod spec;
+
+// Constant
+const ROUTE_API_VERSION: &str = "gateway.networking.k8s.io/v1";
+const ROUTE_KIND: &str = "HTTPRoute";
+
+/// HTTPRoute
+///
+/// # Description
+/// gateway.networking.k8s.io/v1 HTTPRoute. The Gateway API isn't provided by k8s_openapi
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPRoute {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<spec::HTTPRouteSpec>
+}
+
+struct HTTPRouteWrapper {
+    route: HTTPRoute
+}
+
+impl HTTPRouteWrapper {
+    /// New
+    ///
+    /// # Description
+    /// Create a new HTTPRoute object
+    ///
+    /// # Arguments
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn new(object: &Object) -> Self {
+        let route = HTTPRoute {
+            api_version: ROUTE_API_VERSION.to_owned(),
+            kind: ROUTE_KIND.to_owned(),
+            metadata: common::get_metadata_from_object(object),
+            spec: None
+        };
+
+        HTTPRouteWrapper { route }
+    }
+
+    /// Set Spec
+    ///
+    /// # Description
+    /// Set the spec of the HTTPRoute
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
+        let spec = object
+            .spec
+            .to_owned()
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let route = match (spec.network.and_then(|n| n.route), spec.error) {
+            (Some(route), _) => route,
+            (None, Some(err)) => return Err(KubeError::from(err)),
+            (None, None) => return Err(KubeError::from(Error::MissingSpec))
+        };
+
+        self.route.spec = Some(spec::get_http_route_spec(route));
+
+        Ok(self)
+    }
+}
+
+/// Get HTTP Route From Object
+///
+/// # Description
+/// Generate an HTTPRoute resource from a Parser Object
+///
+/// # Arguments
+/// * `object` - Object
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_http_route_from_object(object: Object) -> Result<String, KubeError> {
+    let route = HTTPRouteWrapper::new(&object).set_spec(&object)?;
+    let route_string = serde_yaml::to_string(&route.route)?;
+
+    Ok(route_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_create_http_route() {
+        let template = r#"
+        kind = 'network::httproute'
+        name = 'rusty'
+        metadata = { name = 'rusty' }
+
+        [route]
+            parents = [{ name = 'gateway', namespace = 'infra', section = 'https' }]
+            hostnames = ['foo.bar.com']
+
+            [route.rules]
+                [route.rules.rusty]
+                    matches = [
+                        { path = '/api', headers = { 'x-version' = 'v2' } }
+                    ]
+                    backends = [
+                        { name = 'rusty', port = 8080, weight = 90 },
+                        { resource = { api_group = 'k8s.example.com', kind = 'Bucket', name = 'assets' }, weight = 10 }
+                    ]
+                    request_headers = { add = { 'x-env' = 'prod' } }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let route = HTTPRouteWrapper::new(&object).set_spec(&object).unwrap().route;
+        assert_eq!(route.api_version, "gateway.networking.k8s.io/v1");
+        assert_eq!(route.kind, "HTTPRoute");
+
+        let spec = route.spec.unwrap();
+        let parent = spec.parent_refs.first().unwrap();
+        assert_eq!(parent.name, "gateway");
+        assert_eq!(parent.section_name.as_ref().unwrap(), "https");
+
+        let rule = spec.rules.first().unwrap();
+        let path = rule.matches.first().unwrap().path.as_ref().unwrap();
+        assert_eq!(path.kind, "PathPrefix");
+        assert_eq!(path.value, "/api");
+        assert_eq!(rule.matches.first().unwrap().headers.first().unwrap().name, "x-version");
+
+        let filter = rule.filters.first().unwrap();
+        assert_eq!(filter.kind, "RequestHeaderModifier");
+        assert_eq!(filter.request_header_modifier.as_ref().unwrap().add.first().unwrap().value, "prod");
+
+        let service = rule.backend_refs.first().unwrap();
+        assert_eq!(service.port.unwrap(), 8080);
+        assert_eq!(service.weight.unwrap(), 90);
+
+        let bucket = rule.backend_refs.last().unwrap();
+        assert_eq!(bucket.group.as_ref().unwrap(), "k8s.example.com");
+        assert_eq!(bucket.kind.as_ref().unwrap(), "Bucket");
+        assert!(bucket.port.is_none());
+    }
+
+    #[test]
+    fn expect_to_generate_http_route_yaml() {
+        let template = r#"
+        kind = 'network::httproute'
+        name = 'rusty'
+        metadata = { name = 'rusty' }
+
+        [route]
+            parents = [{ name = 'gateway' }]
+
+            [route.rules]
+                [route.rules.rusty]
+                    backends = [{ name = 'rusty', port = 80 }]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = get_http_route_from_object(object).unwrap();
+
+        assert!(yaml.contains("kind: HTTPRoute"));
+        assert!(yaml.contains("parentRefs"));
+        assert!(yaml.contains("backendRefs"));
+        assert!(!yaml.contains("hostnames"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use std::convert::From;
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use crate::lib::parser::network::route::{
+    HttpRoute,
+    ParentRef,
+    RouteRule,
+    RouteMatch,
+    BackendRef,
+    HeaderModifier
+};
+use crate::lib::parser::network::policy::Port;
+
+// Constant
+const REQUEST_HEADER_MODIFIER: &str = "RequestHeaderModifier";
+
+/// HTTPRouteSpec
+///
+/// # Description
+/// Spec of a gateway.networking.k8s.io/v1 HTTPRoute
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HTTPRouteSpec {
+    pub parent_refs: Vec<ParentReference>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hostnames: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<HTTPRouteRule>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParentReference {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section_name: Option<String>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HTTPRouteRule {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub matches: Vec<HTTPRouteMatch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub filters: Vec<HTTPRouteFilter>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backend_refs: Vec<HTTPBackendRef>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPRouteMatch {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<HTTPPathMatch>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<HTTPHeader>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPPathMatch {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: String
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPHeader {
+    pub name: String,
+    pub value: String
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HTTPRouteFilter {
+    #[serde(rename = "type")]
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_header_modifier: Option<HTTPHeaderFilter>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPHeaderFilter {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub set: Vec<HTTPHeader>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add: Vec<HTTPHeader>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HTTPBackendRef {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<i32>
+}
+
+/// Get Headers
+///
+/// # Arguments
+/// * `headers` - BTreeMap<String, String>
+///
+/// # Return
+/// Vec<HTTPHeader>
+fn get_headers(headers: BTreeMap<String, String>) -> Vec<HTTPHeader> {
+    headers
+        .into_iter()
+        .map(|(name, value)| HTTPHeader { name, value })
+        .collect::<Vec<HTTPHeader>>()
+}
+
+impl From<ParentRef> for ParentReference {
+    fn from(p: ParentRef) -> Self {
+        ParentReference {
+            namespace: p.namespace,
+            name: p.name,
+            section_name: p.section
+        }
+    }
+}
+
+impl From<RouteMatch> for HTTPRouteMatch {
+    fn from(m: RouteMatch) -> Self {
+        let kind = m.kind;
+        HTTPRouteMatch {
+            path: m.path.map(|value| HTTPPathMatch { kind, value }),
+            headers: get_headers(m.headers),
+            method: m.method
+        }
+    }
+}
+
+impl From<HeaderModifier> for HTTPRouteFilter {
+    fn from(h: HeaderModifier) -> Self {
+        HTTPRouteFilter {
+            kind: REQUEST_HEADER_MODIFIER.to_owned(),
+            request_header_modifier: Some(HTTPHeaderFilter {
+                set: get_headers(h.set),
+                add: get_headers(h.add),
+                remove: h.remove
+            })
+        }
+    }
+}
+
+impl From<BackendRef> for HTTPBackendRef {
+    fn from(b: BackendRef) -> Self {
+        if let Some(resource) = b.backend.resource {
+            return HTTPBackendRef {
+                group: resource.api_group,
+                kind: Some(resource.kind),
+                name: resource.name,
+                port: None,
+                weight: b.weight
+            };
+        }
+
+        let port = match b.backend.port {
+            Port::Number(number) => Some(number),
+            Port::Name(_) => None
+        };
+
+        HTTPBackendRef {
+            group: None,
+            kind: None,
+            name: b.backend.name,
+            port,
+            weight: b.weight
+        }
+    }
+}
+
+impl From<RouteRule> for HTTPRouteRule {
+    fn from(r: RouteRule) -> Self {
+        HTTPRouteRule {
+            matches: r.matches.into_iter().map(HTTPRouteMatch::from).collect(),
+            filters: r.request_headers.into_iter().map(HTTPRouteFilter::from).collect(),
+            backend_refs: r.backends.into_iter().map(HTTPBackendRef::from).collect()
+        }
+    }
+}
+
+/// Get HTTP Route Spec
+///
+/// # Arguments
+/// * `route` - HttpRoute
+///
+/// # Return
+/// HTTPRouteSpec
+pub fn get_http_route_spec(route: HttpRoute) -> HTTPRouteSpec {
+    HTTPRouteSpec {
+        parent_refs: route.parents.into_iter().map(ParentReference::from).collect(),
+        hostnames: route.hostnames,
+        rules: route.rules.into_iter().map(HTTPRouteRule::from).collect()
+    }
+}
\ No newline at end of file
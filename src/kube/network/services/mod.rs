@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use k8s_openapi::api::core::v1::Service;
 use crate::kube::common;
 use crate::lib::parser::Object;
@@ -8,6 +9,9 @@ use crate::kube::helper::error::{
 
 mod spec;
 
+// Constant
+const EXTERNAL_NAME: &str = "ExternalName";
+
 struct ServiceWrapper {
     service: Service
 }
@@ -38,7 +42,7 @@ impl ServiceWrapper {
     /// Set Spec
     ///
     /// # Description
-    /// Set the spec of a service
+    /// Set the spec of a service. The selector of the [service] table is used over the selector of the template
     ///
     /// # Arguments
     /// * `mut self` - Self
@@ -57,8 +61,15 @@ impl ServiceWrapper {
             .ok_or_else(|| KubeError::from(spec.error.unwrap()))?;
 
         if let Some(service) = network.service {
+            // an ExternalName service does not select any pods
+            let selector = match (service.selector.to_owned(), service.kind.as_str()) {
+                (_, EXTERNAL_NAME) => BTreeMap::new(),
+                (Some(selector), _) => selector,
+                (None, _) => object.selector.to_owned()
+            };
+
             let mut service_spec = spec::get_service_spec(service);
-            service_spec.selector = object.metadata.to_owned();
+            service_spec.selector = selector;
             self.service.spec = Some(service_spec);
         }
 
@@ -160,4 +171,51 @@ mod tests {
         let service = super::get_service_from_object(object);
         assert!(service.is_ok());
     }
+
+    #[test]
+    fn expect_to_set_selector_and_headless() {
+        let template = "
+            kind = 'network::service'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [service]
+                type = 'ClusterIP'
+                cluster_ip = 'None'
+
+                [service.ports]
+                    [service.ports.http]
+                        protocol = 'TCP'
+                        port = 80
+                        target_port = 'http'
+        ";
+
+        let object = get_parsed_objects(template).unwrap();
+        let spec = ServiceWrapper::new(&object).set_spec(&object).unwrap().service.spec.unwrap();
+
+        assert_eq!(spec.cluster_ip.unwrap(), "None");
+        assert_eq!(spec.selector.len(), 1);
+        assert_eq!(spec.selector.get("name").unwrap(), "rusty");
+        assert_eq!(spec.ports.first().unwrap().target_port.as_ref().unwrap(), &IntOrString::String("http".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_create_external_name_service() {
+        let template = "
+            kind = 'network::service'
+            name = 'database'
+            metadata = { name = 'database' }
+
+            [service]
+                type = 'ExternalName'
+                external_name = 'db.example.com'
+        ";
+
+        let object = get_parsed_objects(template).unwrap();
+        let spec = ServiceWrapper::new(&object).set_spec(&object).unwrap().service.spec.unwrap();
+
+        assert_eq!(spec.type_.unwrap(), "ExternalName");
+        assert_eq!(spec.external_name.unwrap(), "db.example.com");
+        assert!(spec.selector.is_empty());
+    }
 }
\ No newline at end of file
@@ -6,7 +6,8 @@ use k8s_openapi::api::core::v1::{
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use crate::lib::parser::network::service::{
     Service,
-    Port
+    Port,
+    TargetPort
 };
 
 /// Get Service Spec
@@ -24,6 +25,11 @@ pub fn get_service_spec(service: Service) -> ServiceSpec {
     ServiceSpec {
         type_: Some(service.kind),
         ports: ports.unwrap_or_default(),
+        cluster_ip: service.cluster_ip,
+        external_name: service.external_name,
+        session_affinity: service.session_affinity,
+        external_traffic_policy: service.external_traffic_policy,
+        load_balancer_source_ranges: service.load_balancer_source_ranges.unwrap_or_default(),
         ..Default::default()
     }
 }
@@ -59,8 +65,17 @@ impl From<Port> for ServicePort {
             node_port: p.node_port,
             port: p.port as i32,
             protocol: Some(p.protocol),
-            target_port: Some(IntOrString::Int(p.target_port as i32)),
+            target_port: p.target_port.map(IntOrString::from),
             ..Default::default()
         }
     }
+}
+
+impl From<TargetPort> for IntOrString {
+    fn from(p: TargetPort) -> Self {
+        match p {
+            TargetPort::Number(number) => IntOrString::Int(number),
+            TargetPort::Name(name) => IntOrString::String(name)
+        }
+    }
 }
\ No newline at end of file
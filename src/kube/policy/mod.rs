@@ -0,0 +1,190 @@
+use serde::{Serialize, Deserialize};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use k8s_openapi::api::policy::v1beta1::PodDisruptionBudgetSpec;
+use crate::lib::parser::Object;
+use crate::lib::parser::policy::Budget;
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+// Constant
+const PDB_API_VERSION: &str = "policy/v1";
+const PDB_KIND: &str = "PodDisruptionBudget";
+
+/// PodDisruptionBudget
+///
+/// # Description
+/// policy/v1 PodDisruptionBudget. The spec is the same as the policy/v1beta1 spec
+/// which is the latest version provided by k8s_openapi for the supported Kubernetes version
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PodDisruptionBudget {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<PodDisruptionBudgetSpec>
+}
+
+struct PdbWrapper {
+    pdb: PodDisruptionBudget
+}
+
+impl PdbWrapper {
+    /// New
+    ///
+    /// # Description
+    /// Create a new PDB object
+    ///
+    /// # Arguments
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn new(object: &Object) -> Self {
+        let pdb = PodDisruptionBudget {
+            api_version: PDB_API_VERSION.to_owned(),
+            kind: PDB_KIND.to_owned(),
+            metadata: common::get_metadata_from_object(object),
+            spec: None
+        };
+
+        PdbWrapper { pdb }
+    }
+
+    /// Set Spec
+    ///
+    /// # Description
+    /// Set the spec of the PDB. The selector use the same labels as the workloads
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
+        let spec = object
+            .spec
+            .to_owned()
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let disruption = match (spec.disruption, spec.error) {
+            (Some(d), _) => d,
+            (None, Some(err)) => return Err(KubeError::from(err)),
+            (None, None) => return Err(KubeError::from(Error::MissingSpec))
+        };
+
+        self.pdb.spec = Some(PodDisruptionBudgetSpec {
+            min_available: disruption.min_available.map(IntOrString::from),
+            max_unavailable: disruption.max_unavailable.map(IntOrString::from),
+            selector: Some(common::get_label_selector_from_object(object))
+        });
+
+        Ok(self)
+    }
+}
+
+impl From<Budget> for IntOrString {
+    fn from(budget: Budget) -> Self {
+        match budget {
+            Budget::Number(number) => IntOrString::Int(number),
+            Budget::Percent(percent) => IntOrString::String(percent)
+        }
+    }
+}
+
+/// Get Policy From Object
+///
+/// # Description
+/// Generate a policy object
+///
+/// # Arguments
+/// * `object` - Object
+/// * `kind` - String
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_policy_from_object(object: Object, kind: String) -> Result<String, KubeError> {
+    let res = match kind.as_str() {
+        "pdb" => {
+            let wrapper = PdbWrapper::new(&object).set_spec(&object)?;
+            serde_yaml::to_string(&wrapper.pdb)?
+        },
+        _ => "".to_owned()
+    };
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_generate_pdb() {
+        let template = r#"
+        kind = "policy::pdb"
+        name = "nginx"
+        metadata = { name = "nginx", tier = "backend" }
+
+        [disruption]
+            max_unavailable = "25%"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let pdb = PdbWrapper::new(&object).set_spec(&object).unwrap().pdb;
+
+        assert_eq!(pdb.api_version, "policy/v1");
+        assert_eq!(pdb.kind, "PodDisruptionBudget");
+
+        let spec = pdb.spec.unwrap();
+        assert_eq!(spec.max_unavailable.unwrap(), IntOrString::String("25%".to_owned()));
+        assert!(spec.min_available.is_none());
+
+        let selector = spec.selector.unwrap();
+        assert_eq!(selector.match_labels.get("name").unwrap(), "nginx");
+        assert!(!selector.match_labels.contains_key("tier"));
+    }
+
+    #[test]
+    fn expect_to_generate_pdb_yaml() {
+        let template = r#"
+        kind = "policy::pdb"
+        name = "nginx"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            min_available = 2
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = get_policy_from_object(object, "pdb".to_owned()).unwrap();
+
+        assert!(yaml.contains("apiVersion: policy/v1"));
+        assert!(yaml.contains("minAvailable: 2"));
+    }
+
+    #[test]
+    fn expect_to_fail_wrong_budget() {
+        let template = r#"
+        kind = "policy::pdb"
+        name = "nginx"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            min_available = 2
+            max_unavailable = 1
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_policy_from_object(object, "pdb".to_owned());
+
+        assert!(res.is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,441 @@
+use k8s_openapi::api::core::v1::{ServiceAccount, LocalObjectReference};
+use k8s_openapi::api::rbac::v1::{
+    Role,
+    ClusterRole,
+    RoleBinding,
+    ClusterRoleBinding,
+    PolicyRule,
+    RoleRef,
+    Subject
+};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use crate::lib::parser::Object;
+use crate::lib::parser::rbac::{
+    Rbac,
+    Rule as ParserRule,
+    Binding as ParserBinding
+};
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+// Constant
+const RBAC_API_GROUP: &str = "rbac.authorization.k8s.io";
+const SERVICE_ACCOUNT_KIND: &str = "ServiceAccount";
+const ROLE_KIND: &str = "Role";
+const CLUSTER_ROLE_KIND: &str = "ClusterRole";
+
+#[derive(Debug, Default)]
+struct RbacWrapper {
+    service_account: Option<ServiceAccount>,
+    role: Option<Role>,
+    cluster_role: Option<ClusterRole>,
+    role_binding: Option<RoleBinding>,
+    cluster_role_binding: Option<ClusterRoleBinding>
+}
+
+impl RbacWrapper {
+    fn new() -> Self {
+        RbacWrapper {
+            ..Default::default()
+        }
+    }
+
+    /// Set Service Account
+    ///
+    /// # Description
+    /// Create a service account. The [service_account] table is optional
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_service_account(mut self, object: &Object) -> Result<Self, KubeError> {
+        let rbac = get_rbac(object)?;
+        let mut service_account = ServiceAccount {
+            metadata: common::get_metadata_from_object(object),
+            ..Default::default()
+        };
+
+        if let Some(sa) = rbac.service_account {
+            service_account.automount_service_account_token = sa.automount_token;
+            service_account.image_pull_secrets = sa.image_pull_secrets
+                .unwrap_or_default()
+                .into_iter()
+                .map(|name| LocalObjectReference { name: Some(name) })
+                .collect();
+        }
+
+        self.service_account = Some(service_account);
+
+        Ok(self)
+    }
+
+    /// Set Role
+    ///
+    /// # Description
+    /// Create a role with the [rules] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_role(mut self, object: &Object) -> Result<Self, KubeError> {
+        let rbac = get_rbac(object)?;
+        self.role = Some(Role {
+            metadata: common::get_metadata_from_object(object),
+            rules: get_policy_rules(rbac.rules)
+        });
+
+        Ok(self)
+    }
+
+    /// Set Cluster Role
+    ///
+    /// # Description
+    /// Create a cluster role with the [rules] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_cluster_role(mut self, object: &Object) -> Result<Self, KubeError> {
+        let rbac = get_rbac(object)?;
+        self.cluster_role = Some(ClusterRole {
+            metadata: get_cluster_metadata(object),
+            rules: get_policy_rules(rbac.rules),
+            ..Default::default()
+        });
+
+        Ok(self)
+    }
+
+    /// Set Role Binding
+    ///
+    /// # Description
+    /// Create a role binding with the [binding] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_role_binding(mut self, object: &Object) -> Result<Self, KubeError> {
+        let binding = get_rbac(object)?.binding
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        self.role_binding = Some(RoleBinding {
+            metadata: common::get_metadata_from_object(object),
+            role_ref: get_role_ref(&binding, ROLE_KIND),
+            subjects: get_subjects(binding, object)
+        });
+
+        Ok(self)
+    }
+
+    /// Set Cluster Role Binding
+    ///
+    /// # Description
+    /// Create a cluster role binding with the [binding] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_cluster_role_binding(mut self, object: &Object) -> Result<Self, KubeError> {
+        let binding = get_rbac(object)?.binding
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        self.cluster_role_binding = Some(ClusterRoleBinding {
+            metadata: get_cluster_metadata(object),
+            role_ref: get_role_ref(&binding, CLUSTER_ROLE_KIND),
+            subjects: get_subjects(binding, object)
+        });
+
+        Ok(self)
+    }
+}
+
+/// Get Rbac
+///
+/// # Description
+/// Retrieve the rbac definition of the parser object
+///
+/// # Arguments
+/// * `object` - &Object
+///
+/// # Return
+/// Result<Rbac, KubeError>
+fn get_rbac(object: &Object) -> Result<Rbac, KubeError> {
+    let spec = object
+        .spec
+        .to_owned()
+        .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+    match (spec.rbac, spec.error) {
+        (Some(rbac), _) => Ok(rbac),
+        (None, Some(err)) => Err(KubeError::from(err)),
+        (None, None) => Err(KubeError::from(Error::MissingSpec))
+    }
+}
+
+/// Get Cluster Metadata
+///
+/// # Description
+/// Cluster scoped resources does not have a namespace
+///
+/// # Arguments
+/// * `object` - &Object
+///
+/// # Return
+/// ObjectMeta
+fn get_cluster_metadata(object: &Object) -> ObjectMeta {
+    ObjectMeta {
+        namespace: None,
+        ..common::get_metadata_from_object(object)
+    }
+}
+
+/// Get Policy Rules
+///
+/// # Arguments
+/// * `rules` - Option<Vec<ParserRule>>
+///
+/// # Return
+/// Vec<PolicyRule>
+fn get_policy_rules(rules: Option<Vec<ParserRule>>) -> Vec<PolicyRule> {
+    rules
+        .unwrap_or_default()
+        .into_iter()
+        .map(|r| PolicyRule {
+            api_groups: r.api_groups,
+            resources: r.resources,
+            verbs: r.verbs,
+            resource_names: r.resource_names,
+            non_resource_urls: r.non_resource_urls
+        })
+        .collect()
+}
+
+/// Get Role Ref
+///
+/// # Arguments
+/// * `binding` - &ParserBinding
+/// * `default_kind` - &str
+///
+/// # Return
+/// RoleRef
+fn get_role_ref(binding: &ParserBinding, default_kind: &str) -> RoleRef {
+    RoleRef {
+        api_group: RBAC_API_GROUP.to_owned(),
+        kind: binding.role.kind.to_owned().unwrap_or_else(|| default_kind.to_owned()),
+        name: binding.role.name.to_owned()
+    }
+}
+
+/// Get Subjects
+///
+/// # Description
+/// Users & groups use the rbac api group. Service accounts use the namespace of the template
+/// when the namespace isn't specified
+///
+/// # Arguments
+/// * `binding` - ParserBinding
+/// * `object` - &Object
+///
+/// # Return
+/// Vec<Subject>
+fn get_subjects(binding: ParserBinding, object: &Object) -> Vec<Subject> {
+    binding.subjects
+        .into_iter()
+        .map(|s| {
+            if s.kind == SERVICE_ACCOUNT_KIND {
+                return Subject {
+                    namespace: s.namespace.or_else(|| object.namespace.to_owned()),
+                    kind: s.kind,
+                    name: s.name,
+                    api_group: None
+                };
+            }
+
+            Subject {
+                api_group: Some(RBAC_API_GROUP.to_owned()),
+                kind: s.kind,
+                name: s.name,
+                namespace: s.namespace
+            }
+        })
+        .collect()
+}
+
+/// Get Rbac From Object
+///
+/// # Description
+/// Generate a service account, role, cluster role or binding
+///
+/// # Arguments
+/// * `object` - Object
+/// * `kind` - String
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_rbac_from_object(object: Object, kind: String) -> Result<String, KubeError> {
+    let rbac_str = match kind.as_str() {
+        "serviceaccount" => {
+            let rbac = RbacWrapper::new().set_service_account(&object)?;
+            serde_yaml::to_string(&rbac.service_account)?
+        },
+        "role" => {
+            let rbac = RbacWrapper::new().set_role(&object)?;
+            serde_yaml::to_string(&rbac.role)?
+        },
+        "clusterrole" => {
+            let rbac = RbacWrapper::new().set_cluster_role(&object)?;
+            serde_yaml::to_string(&rbac.cluster_role)?
+        },
+        "rolebinding" => {
+            let rbac = RbacWrapper::new().set_role_binding(&object)?;
+            serde_yaml::to_string(&rbac.role_binding)?
+        },
+        "clusterrolebinding" => {
+            let rbac = RbacWrapper::new().set_cluster_role_binding(&object)?;
+            serde_yaml::to_string(&rbac.cluster_role_binding)?
+        },
+        _ => "".to_owned()
+    };
+
+    Ok(rbac_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_create_service_account() {
+        let template = r#"
+        kind = "rbac::serviceaccount"
+        name = "nginx"
+        namespace = "rusty"
+        metadata = { name = "nginx" }
+
+        [service_account]
+            automount_token = false
+            image_pull_secrets = ["regcred"]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let rbac = RbacWrapper::new().set_service_account(&object).unwrap();
+        let sa = rbac.service_account.unwrap();
+
+        assert_eq!(sa.metadata.namespace.unwrap(), "rusty");
+        assert!(!sa.automount_service_account_token.unwrap());
+        assert_eq!(sa.image_pull_secrets.first().unwrap().name.as_ref().unwrap(), "regcred");
+    }
+
+    #[test]
+    fn expect_to_create_cluster_role() {
+        let template = r#"
+        kind = "rbac::clusterrole"
+        name = "pod-reader"
+        namespace = "rusty"
+        metadata = { name = "pod-reader" }
+
+        [rules]
+            [rules.pods]
+                api_groups = [""]
+                resources = ["pods"]
+                verbs = ["get", "list", "watch"]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let rbac = RbacWrapper::new().set_cluster_role(&object).unwrap();
+        let role = rbac.cluster_role.unwrap();
+
+        assert!(role.metadata.namespace.is_none());
+        assert_eq!(role.rules.len(), 1);
+
+        let rule = role.rules.first().unwrap();
+        assert_eq!(rule.api_groups, vec![""]);
+        assert_eq!(rule.verbs, vec!["get", "list", "watch"]);
+    }
+
+    #[test]
+    fn expect_to_create_role_binding() {
+        let template = r#"
+        kind = "rbac::rolebinding"
+        name = "read-pods"
+        namespace = "rusty"
+        metadata = { name = "read-pods" }
+
+        [binding]
+            role = { name = "pod-reader" }
+            subjects = [
+                { kind = "ServiceAccount", name = "nginx" },
+                { kind = "User", name = "jane" }
+            ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let rbac = RbacWrapper::new().set_role_binding(&object).unwrap();
+        let binding = rbac.role_binding.unwrap();
+
+        assert_eq!(binding.role_ref.kind, "Role");
+        assert_eq!(binding.role_ref.name, "pod-reader");
+        assert_eq!(binding.role_ref.api_group, "rbac.authorization.k8s.io");
+
+        let sa = binding.subjects.first().unwrap();
+        assert_eq!(sa.namespace.as_ref().unwrap(), "rusty");
+        assert!(sa.api_group.is_none());
+
+        let user = binding.subjects.last().unwrap();
+        assert_eq!(user.api_group.as_ref().unwrap(), "rbac.authorization.k8s.io");
+    }
+
+    #[test]
+    fn expect_to_generate_cluster_role_binding_string() {
+        let template = r#"
+        kind = "rbac::clusterrolebinding"
+        name = "read-pods"
+        metadata = { name = "read-pods" }
+
+        [binding]
+            role = { name = "pod-reader" }
+            subjects = [
+                { kind = "Group", name = "developers" }
+            ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_rbac_from_object(object, "clusterrolebinding".to_owned()).unwrap();
+
+        assert!(res.contains("kind: ClusterRoleBinding"));
+        assert!(res.contains("kind: ClusterRole\n"));
+    }
+
+    #[test]
+    fn expect_to_fail_missing_binding() {
+        let template = r#"
+        kind = "rbac::rolebinding"
+        name = "read-pods"
+        metadata = { name = "read-pods" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_rbac_from_object(object, "rolebinding".to_owned());
+
+        assert!(res.is_err());
+    }
+}
\ No newline at end of file
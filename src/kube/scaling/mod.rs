@@ -0,0 +1,346 @@
+use serde::{Serialize, Deserialize};
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::api::autoscaling::v2beta2::{
+    HorizontalPodAutoscalerSpec,
+    HorizontalPodAutoscalerBehavior,
+    CrossVersionObjectReference,
+    HPAScalingRules,
+    HPAScalingPolicy,
+    MetricSpec,
+    MetricTarget,
+    ResourceMetricSource
+};
+use crate::lib::parser::Object;
+use crate::lib::parser::scaling::{Scaling, Rules};
+use crate::kube::common;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+// Constant
+const HPA_API_VERSION: &str = "autoscaling/v2";
+const HPA_KIND: &str = "HorizontalPodAutoscaler";
+const TARGET_API_VERSION: &str = "apps/v1";
+const RESOURCE_METRIC: &str = "Resource";
+const UTILIZATION_TARGET: &str = "Utilization";
+
+/// HorizontalPodAutoscaler
+///
+/// # Description
+/// autoscaling/v2 HorizontalPodAutoscaler. The spec is the same as the autoscaling/v2beta2
+/// spec which is the latest version provided by k8s_openapi for the supported Kubernetes version
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HorizontalPodAutoscaler {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub metadata: ObjectMeta,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<HorizontalPodAutoscalerSpec>
+}
+
+struct HpaWrapper {
+    hpa: HorizontalPodAutoscaler
+}
+
+impl HpaWrapper {
+    /// New
+    ///
+    /// # Description
+    /// Create a new HPA object
+    ///
+    /// # Arguments
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Self
+    fn new(object: &Object) -> Self {
+        let hpa = HorizontalPodAutoscaler {
+            api_version: HPA_API_VERSION.to_owned(),
+            kind: HPA_KIND.to_owned(),
+            metadata: common::get_metadata_from_object(object),
+            spec: None
+        };
+
+        HpaWrapper { hpa }
+    }
+
+    /// Set Spec
+    ///
+    /// # Description
+    /// Set the spec of the HPA
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
+        let spec = object
+            .spec
+            .to_owned()
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let scaling = match (spec.scaling, spec.error) {
+            (Some(s), _) => s,
+            (None, Some(err)) => return Err(KubeError::from(err)),
+            (None, None) => return Err(KubeError::from(Error::MissingSpec))
+        };
+
+        let name = scaling.target.name
+            .to_owned()
+            .or_else(|| object.metadata.get("name").cloned())
+            .or_else(|| object.name.to_owned())
+            .ok_or_else(|| KubeError::from(Error::MissingTargetName))?;
+
+        self.hpa.spec = Some(HorizontalPodAutoscalerSpec {
+            scale_target_ref: CrossVersionObjectReference {
+                api_version: Some(TARGET_API_VERSION.to_owned()),
+                kind: get_target_kind(&scaling.target.kind),
+                name
+            },
+            min_replicas: scaling.min_replicas,
+            max_replicas: scaling.max_replicas,
+            metrics: get_metrics(&scaling),
+            behavior: get_behavior(&scaling)
+        });
+
+        Ok(self)
+    }
+}
+
+/// Get Target Kind
+///
+/// # Description
+/// Convert the kind of a workload template (i.e: deployment) to a Kubernetes kind
+///
+/// # Arguments
+/// * `kind` - &str
+///
+/// # Return
+/// String
+fn get_target_kind(kind: &str) -> String {
+    match kind.to_lowercase().as_str() {
+        "deployment" => "Deployment".to_owned(),
+        "statefulset" => "StatefulSet".to_owned(),
+        "replicaset" => "ReplicaSet".to_owned(),
+        _ => kind.to_owned()
+    }
+}
+
+/// Get Metrics
+///
+/// # Description
+/// Create the CPU & memory utilization metrics
+///
+/// # Arguments
+/// * `scaling` - &Scaling
+///
+/// # Return
+/// Vec<MetricSpec>
+fn get_metrics(scaling: &Scaling) -> Vec<MetricSpec> {
+    vec![("cpu", scaling.cpu), ("memory", scaling.memory)]
+        .into_iter()
+        .filter_map(|(name, utilization)| utilization.map(|u| MetricSpec {
+            type_: RESOURCE_METRIC.to_owned(),
+            resource: Some(ResourceMetricSource {
+                name: name.to_owned(),
+                target: MetricTarget {
+                    type_: UTILIZATION_TARGET.to_owned(),
+                    average_utilization: Some(u),
+                    ..Default::default()
+                }
+            }),
+            ..Default::default()
+        }))
+        .collect()
+}
+
+/// Get Behavior
+///
+/// # Description
+/// Create the scale up & scale down behavior
+///
+/// # Arguments
+/// * `scaling` - &Scaling
+///
+/// # Return
+/// Option<HorizontalPodAutoscalerBehavior>
+fn get_behavior(scaling: &Scaling) -> Option<HorizontalPodAutoscalerBehavior> {
+    if scaling.scale_up.is_none() && scaling.scale_down.is_none() {
+        return None;
+    }
+
+    Some(HorizontalPodAutoscalerBehavior {
+        scale_up: scaling.scale_up.as_ref().map(get_scaling_rules),
+        scale_down: scaling.scale_down.as_ref().map(get_scaling_rules)
+    })
+}
+
+/// Get Scaling Rules
+///
+/// # Arguments
+/// * `rules` - &Rules
+///
+/// # Return
+/// HPAScalingRules
+fn get_scaling_rules(rules: &Rules) -> HPAScalingRules {
+    HPAScalingRules {
+        stabilization_window_seconds: rules.stabilization_window,
+        select_policy: rules.select_policy.to_owned(),
+        policies: rules.policies
+            .iter()
+            .map(|p| HPAScalingPolicy {
+                type_: p.kind.to_owned(),
+                value: p.value,
+                period_seconds: p.period
+            })
+            .collect()
+    }
+}
+
+/// Get Scaling From Object
+///
+/// # Description
+/// Generate a scaling object
+///
+/// # Arguments
+/// * `object` - Object
+/// * `kind` - String
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_scaling_from_object(object: Object, kind: String) -> Result<String, KubeError> {
+    let res = match kind.as_str() {
+        "hpa" => {
+            let wrapper = HpaWrapper::new(&object).set_spec(&object)?;
+            serde_yaml::to_string(&wrapper.hpa)?
+        },
+        _ => "".to_owned()
+    };
+
+    Ok(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_generate_hpa() {
+        let template = r#"
+        kind = "scaling::hpa"
+        name = "nginx"
+        metadata = { name = "nginx", tier = "backend" }
+
+        [autoscaling]
+            min_replicas = 2
+            max_replicas = 10
+            cpu = 80
+            memory = 70
+
+            [autoscaling.scale_down]
+                stabilization_window = 300
+                policies = [
+                    { type = "Percent", value = 10, period = 60 }
+                ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let wrapper = HpaWrapper::new(&object).set_spec(&object).unwrap();
+        let hpa = wrapper.hpa;
+
+        assert_eq!(hpa.api_version, "autoscaling/v2");
+        assert_eq!(hpa.kind, "HorizontalPodAutoscaler");
+
+        let spec = hpa.spec.unwrap();
+        assert_eq!(spec.scale_target_ref.kind, "Deployment");
+        assert_eq!(spec.scale_target_ref.name, "nginx");
+        assert_eq!(spec.scale_target_ref.api_version.unwrap(), "apps/v1");
+        assert_eq!(spec.min_replicas.unwrap(), 2);
+        assert_eq!(spec.max_replicas, 10);
+        assert_eq!(spec.metrics.len(), 2);
+
+        let cpu = spec.metrics.first().unwrap().resource.as_ref().unwrap();
+        assert_eq!(cpu.name, "cpu");
+        assert_eq!(cpu.target.average_utilization.unwrap(), 80);
+
+        let behavior = spec.behavior.unwrap();
+        assert!(behavior.scale_up.is_none());
+
+        let scale_down = behavior.scale_down.unwrap();
+        assert_eq!(scale_down.stabilization_window_seconds.unwrap(), 300);
+        assert_eq!(scale_down.policies.first().unwrap().period_seconds, 60);
+    }
+
+    #[test]
+    fn expect_to_generate_hpa_yaml() {
+        let template = r#"
+        kind = "scaling::hpa"
+        name = "redis"
+        metadata = { name = "redis-hpa" }
+
+        [autoscaling]
+            target = { kind = "statefulset", name = "redis" }
+            max_replicas = 3
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = get_scaling_from_object(object, "hpa".to_owned()).unwrap();
+
+        assert!(yaml.contains("apiVersion: autoscaling/v2"));
+        assert!(yaml.contains("kind: StatefulSet"));
+        assert!(yaml.contains("name: redis\n"));
+    }
+
+    #[test]
+    fn expect_to_fail_missing_autoscaling() {
+        let template = r#"
+        kind = "scaling::hpa"
+        name = "nginx"
+        metadata = { name = "nginx" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_scaling_from_object(object, "hpa".to_owned());
+
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn expect_to_fallback_to_template_name() {
+        let template = r#"
+        kind = "scaling::hpa"
+        name = "nginx"
+        metadata = { tier = "backend" }
+
+        [autoscaling]
+            max_replicas = 3
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let wrapper = HpaWrapper::new(&object).set_spec(&object).unwrap();
+        let target = wrapper.hpa.spec.unwrap().scale_target_ref;
+
+        assert_eq!(target.name, "nginx");
+    }
+
+    #[test]
+    fn expect_to_fail_missing_target_name() {
+        let template = r#"
+        kind = "scaling::hpa"
+        metadata = { tier = "backend" }
+
+        [autoscaling]
+            max_replicas = 3
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let res = get_scaling_from_object(object, "hpa".to_owned());
+
+        assert!(res.is_err());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,206 @@
+use k8s_openapi::api::core::v1::PersistentVolumeClaim;
+use k8s_openapi::api::storage::v1::StorageClass;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use crate::lib::parser::Object;
+use crate::lib::parser::storage::Storage;
+use crate::kube::common;
+use crate::kube::workload::volumes::claim;
+use crate::kube::helper::error::{
+    KubeError,
+    common::Error
+};
+
+#[derive(Debug, Default)]
+struct StorageWrapper {
+    pvc: Option<PersistentVolumeClaim>,
+    class: Option<StorageClass>
+}
+
+impl StorageWrapper {
+    fn new() -> Self {
+        StorageWrapper {
+            ..Default::default()
+        }
+    }
+
+    /// Set Pvc
+    ///
+    /// # Description
+    /// Create a standalone persistent volume claim with the [claim] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_pvc(mut self, object: &Object) -> Result<Self, KubeError> {
+        let parser_pvc = get_storage(object)?
+            .claim
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        let mut pvc = claim::get_pvc(&parser_pvc);
+        pvc.metadata = common::get_metadata_from_object(object);
+        self.pvc = Some(pvc);
+
+        Ok(self)
+    }
+
+    /// Set Class
+    ///
+    /// # Description
+    /// Create a storage class with the [storage_class] table. A storage class does not have a namespace
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `object` - &Object
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    fn set_class(mut self, object: &Object) -> Result<Self, KubeError> {
+        let class = get_storage(object)?
+            .class
+            .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+        self.class = Some(StorageClass {
+            metadata: ObjectMeta {
+                namespace: None,
+                ..common::get_metadata_from_object(object)
+            },
+            provisioner: class.provisioner,
+            parameters: class.parameters.unwrap_or_default(),
+            reclaim_policy: class.reclaim_policy,
+            volume_binding_mode: class.volume_binding_mode,
+            allow_volume_expansion: class.allow_volume_expansion,
+            mount_options: class.mount_options.unwrap_or_default(),
+            ..Default::default()
+        });
+
+        Ok(self)
+    }
+}
+
+/// Get Storage
+///
+/// # Arguments
+/// * `object` - &Object
+///
+/// # Return
+/// Result<Storage, KubeError>
+fn get_storage(object: &Object) -> Result<Storage, KubeError> {
+    let spec = object
+        .spec
+        .to_owned()
+        .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
+
+    match (spec.storage, spec.error) {
+        (Some(storage), _) => Ok(storage),
+        (None, Some(err)) => Err(KubeError::from(err)),
+        (None, None) => Err(KubeError::from(Error::MissingSpec))
+    }
+}
+
+/// Get Storage From Object
+///
+/// # Description
+/// Generate a persistent volume claim or a storage class
+///
+/// # Arguments
+/// * `object` - Object
+/// * `kind` - String
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn get_storage_from_object(object: Object, kind: String) -> Result<String, KubeError> {
+    let storage_str = match kind.as_str() {
+        "pvc" => {
+            let storage = StorageWrapper::new().set_pvc(&object)?;
+            serde_yaml::to_string(&storage.pvc)?
+        },
+        "class" => {
+            let storage = StorageWrapper::new().set_class(&object)?;
+            serde_yaml::to_string(&storage.class)?
+        },
+        _ => "".to_owned()
+    };
+
+    Ok(storage_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_create_pvc() {
+        let template = r#"
+        kind = "storage::pvc"
+        name = "data"
+        namespace = "rusty"
+        metadata = { name = "data", tier = "backend" }
+
+        [claim]
+            access_modes = ["ReadWriteOnce"]
+            class_name = "ssd"
+            data_source = { kind = "VolumeSnapshot", name = "data-snapshot", api_group = "snapshot.storage.k8s.io" }
+            resources_request = [
+                { key_name = "storage", value = "10Gi" }
+            ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let pvc = StorageWrapper::new().set_pvc(&object).unwrap().pvc.unwrap();
+
+        assert_eq!(pvc.metadata.name.unwrap(), "data");
+        assert_eq!(pvc.metadata.namespace.unwrap(), "rusty");
+        assert_eq!(pvc.metadata.labels.get("tier").unwrap(), "backend");
+
+        let spec = pvc.spec.unwrap();
+        assert_eq!(spec.access_modes, vec!["ReadWriteOnce"]);
+        assert_eq!(spec.storage_class_name.unwrap(), "ssd");
+
+        let data_source = spec.data_source.unwrap();
+        assert_eq!(data_source.kind, "VolumeSnapshot");
+        assert_eq!(data_source.api_group.unwrap(), "snapshot.storage.k8s.io");
+
+        let requests = spec.resources.unwrap().requests;
+        assert_eq!(requests.get("storage").unwrap(), &Quantity("10Gi".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_create_storage_class() {
+        let template = r#"
+        kind = "storage::class"
+        name = "ssd"
+        namespace = "rusty"
+        metadata = { name = "ssd" }
+
+        [storage_class]
+            provisioner = "kubernetes.io/gce-pd"
+            parameters = { type = "pd-ssd" }
+            volume_binding_mode = "WaitForFirstConsumer"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let class = StorageWrapper::new().set_class(&object).unwrap().class.unwrap();
+
+        assert!(class.metadata.namespace.is_none());
+        assert_eq!(class.provisioner, "kubernetes.io/gce-pd");
+        assert_eq!(class.parameters.get("type").unwrap(), "pd-ssd");
+        assert_eq!(class.volume_binding_mode.unwrap(), "WaitForFirstConsumer");
+    }
+
+    #[test]
+    fn expect_to_fail_missing_claim() {
+        let template = r#"
+        kind = "storage::pvc"
+        name = "data"
+        metadata = { name = "data" }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        assert!(get_storage_from_object(object, "pvc".to_owned()).is_err());
+    }
+}
\ No newline at end of file
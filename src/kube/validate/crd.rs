@@ -0,0 +1,263 @@
+use serde::Deserialize;
+use serde_json::{Value, Map};
+use crate::kube::helper::error::KubeError;
+use super::schema::Validator;
+
+// Constant
+const CRD_KIND: &str = "CustomResourceDefinition";
+const API_VERSION_SEPARATOR: &str = "/";
+// Fields handled by Kubernetes which aren't described by the openAPIV3Schema
+const ROOT_FIELDS: [&str; 3] = ["apiVersion", "kind", "metadata"];
+
+/// CrdSchema
+///
+/// # Description
+/// openAPIV3Schema of a version of a CustomResourceDefinition
+struct CrdSchema {
+    group: String,
+    version: String,
+    kind: String,
+    storage: bool,
+    schema: Value
+}
+
+/// CrdSchemas
+///
+/// # Description
+/// List of schemas loaded from CustomResourceDefinition files
+#[derive(Default)]
+pub struct CrdSchemas {
+    schemas: Vec<CrdSchema>
+}
+
+impl CrdSchemas {
+    /// New
+    ///
+    /// # Description
+    /// Load the schemas of the CustomResourceDefinitions contained in the files. Documents
+    /// which aren't CustomResourceDefinition are ignored
+    ///
+    /// # Arguments
+    /// * `contents` - &[String]
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    pub fn new(contents: &[String]) -> Result<Self, KubeError> {
+        let mut crds = CrdSchemas::default();
+        for content in contents {
+            for document in serde_yaml::Deserializer::from_str(content) {
+                let value = Value::deserialize(document)?;
+                if value.get("kind").and_then(|k| k.as_str()) == Some(CRD_KIND) {
+                    crds.add_definition(&value);
+                }
+            }
+        }
+
+        Ok(crds)
+    }
+
+    /// Add Definition
+    ///
+    /// # Description
+    /// Add the schema of each versions of a CustomResourceDefinition. Both apiextensions.k8s.io/v1
+    /// and apiextensions.k8s.io/v1beta1 (spec.validation) are supported
+    ///
+    /// # Arguments
+    /// * `crd` - &Value
+    fn add_definition(&mut self, crd: &Value) {
+        let spec = match crd.get("spec") {
+            Some(s) => s,
+            None => return
+        };
+
+        let group = get_str(spec, "/group");
+        let kind = get_str(spec, "/names/kind");
+        let shared_schema = spec.pointer("/validation/openAPIV3Schema");
+
+        let versions = spec.get("versions")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        if versions.is_empty() {
+            if let Some(schema) = shared_schema {
+                self.schemas.push(CrdSchema {
+                    group,
+                    version: get_str(spec, "/version"),
+                    kind,
+                    storage: true,
+                    schema: schema.to_owned()
+                });
+            }
+
+            return;
+        }
+
+        for version in versions {
+            let schema = version.pointer("/schema/openAPIV3Schema").or(shared_schema);
+            if let Some(schema) = schema {
+                self.schemas.push(CrdSchema {
+                    group: group.to_owned(),
+                    version: get_str(&version, "/name"),
+                    kind: kind.to_owned(),
+                    storage: version.get("storage").and_then(|s| s.as_bool()).unwrap_or(false),
+                    schema: schema.to_owned()
+                });
+            }
+        }
+    }
+
+    /// Get Schema
+    ///
+    /// # Description
+    /// Retrieve the schema matching the apiVersion & kind. When the apiVersion is missing
+    /// the storage version of the kind is used
+    ///
+    /// # Arguments
+    /// * `api_version` - &str
+    /// * `kind` - &str
+    ///
+    /// # Return
+    /// Option<&Value>
+    fn get_schema(&self, api_version: &str, kind: &str) -> Option<&Value> {
+        let mut schemas = self.schemas.iter().filter(|s| s.kind == kind);
+        if api_version.is_empty() {
+            let candidates = schemas.collect::<Vec<&CrdSchema>>();
+            return candidates.iter()
+                .find(|s| s.storage)
+                .or_else(|| candidates.first())
+                .map(|s| &s.schema);
+        }
+
+        let (group, version) = api_version
+            .split_once(API_VERSION_SEPARATOR)
+            .unwrap_or(("", api_version));
+
+        schemas
+            .find(|s| s.group == group && s.version == version)
+            .map(|s| &s.schema)
+    }
+
+    /// Validate
+    ///
+    /// # Description
+    /// Validate a custom resource with the openAPIV3Schema of it's CustomResourceDefinition.
+    /// The paths of the errors match the keys of the TOML template (i.e: spec.templates[0].name)
+    ///
+    /// # Arguments
+    /// * `value` - &Value
+    /// * `api_version` - &str
+    /// * `kind` - &str
+    ///
+    /// # Return
+    /// Option<Vec<String>> None if no CustomResourceDefinition match the kind
+    pub fn validate(&self, value: &Value, api_version: &str, kind: &str) -> Option<Vec<String>> {
+        let schema = self.get_schema(api_version, kind)?;
+        let resource = value.as_object()
+            .map(|map| map.iter()
+                .filter(|(key, item)| !ROOT_FIELDS.contains(&key.as_str()) && !item.is_null())
+                .map(|(key, item)| (key.to_owned(), item.to_owned()))
+                .collect::<Map<String, Value>>()
+            )
+            .unwrap_or_default();
+
+        let mut errors = Vec::new();
+        Validator::new(None).validate(&Value::Object(resource), schema, "", &mut errors);
+
+        Some(errors)
+    }
+}
+
+/// Get Str
+///
+/// # Arguments
+/// * `value` - &Value
+/// * `pointer` - &str
+///
+/// # Return
+/// String
+fn get_str(value: &Value, pointer: &str) -> String {
+    value.pointer(pointer)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    const CRD: &str = "
+apiVersion: apiextensions.k8s.io/v1
+kind: CustomResourceDefinition
+metadata:
+  name: managedcertificates.networking.gke.io
+spec:
+  group: networking.gke.io
+  names:
+    kind: ManagedCertificate
+    plural: managedcertificates
+  scope: Namespaced
+  versions:
+    - name: v1
+      served: true
+      storage: true
+      schema:
+        openAPIV3Schema:
+          type: object
+          properties:
+            spec:
+              type: object
+              required:
+                - domains
+              properties:
+                domains:
+                  type: array
+                  items:
+                    type: string
+            status:
+              type: object
+              x-kubernetes-preserve-unknown-fields: true
+";
+
+    fn get_crds() -> CrdSchemas {
+        CrdSchemas::new(&[CRD.to_owned()]).unwrap()
+    }
+
+    #[test]
+    fn expect_to_validate_custom_resource() {
+        let value = json!({
+            "apiVersion": "networking.gke.io/v1",
+            "kind": "ManagedCertificate",
+            "metadata": { "name": "rusty-certificate" },
+            "spec": { "domains": ["rusty-dev.co.kr"] }
+        });
+
+        let errors = get_crds().validate(&value, "networking.gke.io/v1", "ManagedCertificate");
+        assert!(errors.is_some());
+        assert!(errors.unwrap().is_empty());
+    }
+
+    #[test]
+    fn expect_to_return_errors_with_toml_path() {
+        let value = json!({
+            "kind": "ManagedCertificate",
+            "metadata": { "name": "rusty-certificate" },
+            "spec": { "domain": ["rusty-dev.co.kr"] }
+        });
+
+        let errors = get_crds().validate(&value, "", "ManagedCertificate").unwrap();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(&"spec: missing required field `domains`".to_owned()));
+        assert!(errors.contains(&"spec.domain: unknown field".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_not_find_crd() {
+        let value = json!({ "apiVersion": "networking.gke.io/v2", "kind": "ManagedCertificate" });
+        let errors = get_crds().validate(&value, "networking.gke.io/v2", "ManagedCertificate");
+
+        assert!(errors.is_none());
+    }
+}
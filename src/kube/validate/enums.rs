@@ -0,0 +1,119 @@
+use serde_json::Value;
+use crate::kube::helper::error::validate::Error;
+use super::schema::{join_path, push_error};
+
+// Fields which contain free form keys. Their content is never checked
+const SKIPPED_FIELDS: [&str; 6] = [
+    "metadata",
+    "data",
+    "stringData",
+    "binaryData",
+    "matchLabels",
+    "nodeSelector"
+];
+
+// Enum values supported by Kubernetes for common fields. The OpenAPI schema
+// served by Kubernetes does not describe them
+const ENUMS: [(&str, &[&str]); 10] = [
+    ("imagePullPolicy", &["Always", "Never", "IfNotPresent"]),
+    ("protocol", &["TCP", "UDP", "SCTP"]),
+    ("restartPolicy", &["Always", "OnFailure", "Never"]),
+    ("pathType", &["Exact", "Prefix", "ImplementationSpecific"]),
+    ("dnsPolicy", &["ClusterFirst", "ClusterFirstWithHostNet", "Default", "None"]),
+    ("sessionAffinity", &["ClientIP", "None"]),
+    ("externalTrafficPolicy", &["Cluster", "Local"]),
+    ("podManagementPolicy", &["OrderedReady", "Parallel"]),
+    ("volumeMode", &["Filesystem", "Block"]),
+    ("accessModes", &["ReadWriteOnce", "ReadOnlyMany", "ReadWriteMany", "ReadWriteOncePod"])
+];
+
+/// Validate
+///
+/// # Description
+/// Check the value of the enum fields of a Kubernetes object
+///
+/// # Arguments
+/// * `value` - &Value
+/// * `path` - &str
+/// * `errors` - &mut Vec<String>
+pub fn validate(value: &Value, path: &str, errors: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, item) in map {
+                if SKIPPED_FIELDS.contains(&key.as_str()) {
+                    continue;
+                }
+
+                let item_path = join_path(path, key);
+                match ENUMS.iter().find(|(field, _)| field == key) {
+                    Some((_, allowed)) => check_value(item, allowed, &item_path, errors),
+                    None => validate(item, &item_path, errors)
+                }
+            }
+        },
+        Value::Array(items) => {
+            for (idx, item) in items.iter().enumerate() {
+                validate(item, &format!("{}[{}]", path, idx), errors);
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Check Value
+///
+/// # Description
+/// Check that a string or each string of an array is part of the allowed values
+///
+/// # Arguments
+/// * `value` - &Value
+/// * `allowed` - &[&str]
+/// * `path` - &str
+/// * `errors` - &mut Vec<String>
+fn check_value(value: &Value, allowed: &[&str], path: &str, errors: &mut Vec<String>) {
+    match value {
+        Value::String(s) if !allowed.contains(&s.as_str()) => {
+            push_error(errors, path, Error::UnsupportedValue(s.to_owned(), allowed.join(", ")));
+        },
+        Value::Array(items) => {
+            for (idx, item) in items.iter().enumerate() {
+                check_value(item, allowed, &format!("{}[{}]", path, idx), errors);
+            }
+        },
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn expect_to_return_unsupported_values() {
+        let value = json!({
+            "metadata": { "labels": { "protocol": "http" } },
+            "spec": {
+                "containers": [{
+                    "name": "nginx",
+                    "imagePullPolicy": "Sometimes",
+                    "ports": [{ "containerPort": 80, "protocol": "TCP" }]
+                }],
+                "volumeClaimTemplates": [{
+                    "spec": { "accessModes": ["ReadWriteOnce", "WriteOnly"] }
+                }]
+            }
+        });
+
+        let mut errors = Vec::new();
+        validate(&value, "", &mut errors);
+
+        assert_eq!(errors.len(), 2);
+        assert!(errors.contains(
+            &"spec.containers[0].imagePullPolicy: unsupported value `Sometimes`, expected one of: Always, Never, IfNotPresent".to_owned()
+        ));
+        assert!(errors.contains(
+            &"spec.volumeClaimTemplates[0].spec.accessModes[1]: unsupported value `WriteOnly`, expected one of: ReadWriteOnce, ReadOnlyMany, ReadWriteMany, ReadWriteOncePod".to_owned()
+        ));
+    }
+}
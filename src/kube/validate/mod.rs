@@ -0,0 +1,110 @@
+pub mod schema;
+pub mod crd;
+mod typed;
+mod enums;
+
+use serde::Deserialize;
+use serde_json::Value;
+use schema::OpenApiSchema;
+use crd::CrdSchemas;
+use super::helper::error::{
+    KubeError,
+    validate::Error
+};
+
+/// Report
+///
+/// # Description
+/// Result of the validation of a rendered template
+#[derive(Debug, Default)]
+pub struct Report {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>
+}
+
+/// Validate Offline
+///
+/// # Description
+/// Validate every documents of a rendered YAML template without calling a cluster
+///     - With a schema, documents are checked against the OpenAPI definition of their kind
+///     - Without a schema, built-in kinds are checked with the k8s_openapi structs
+///     - Custom resources are checked with the schema of their CustomResourceDefinition
+/// Kinds which can't be checked are reported as a warning
+///
+/// # Arguments
+/// * `content` - &str
+/// * `schema` - Option<&OpenApiSchema>
+/// * `crds` - Option<&CrdSchemas>
+///
+/// # Return
+/// Result<Report, KubeError>
+pub fn validate_offline(
+    content: &str,
+    schema: Option<&OpenApiSchema>,
+    crds: Option<&CrdSchemas>
+) -> Result<Report, KubeError> {
+    let mut report = Report::default();
+    for document in serde_yaml::Deserializer::from_str(content) {
+        let value = Value::deserialize(document)?;
+        if value.is_null() {
+            continue;
+        }
+
+        let api_version = value.get("apiVersion").and_then(|v| v.as_str()).unwrap_or_default();
+        let kind = value.get("kind").and_then(|v| v.as_str()).unwrap_or_default();
+        let name = value.pointer("/metadata/name").and_then(|v| v.as_str()).unwrap_or_default();
+
+        let mut errors = crds
+            .and_then(|c| c.validate(&value, api_version, kind))
+            .or_else(|| match schema {
+                Some(s) => s.validate(&value, api_version, kind),
+                None => typed::validate(&value, api_version, kind)
+            });
+
+        match errors.as_mut() {
+            Some(errors) => {
+                if typed::is_builtin(api_version, kind) {
+                    enums::validate(&value, "", errors);
+                }
+
+                report.errors.extend(errors.iter().map(|err| format!("{} {}: {}", kind, name, err)));
+            },
+            None => {
+                let gvk = format!("{} {}", api_version, kind);
+                report.warnings.push(Error::SchemaNotFound(gvk.trim().to_owned()).to_string());
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expect_to_validate_multiple_documents() {
+        let content = "
+apiVersion: v1
+kind: Service
+metadata:
+  name: nginx
+spec:
+  ports:
+    - port: 80
+      protocol: HTTP
+---
+apiVersion: argoproj.io/v1alpha1
+kind: Workflow
+metadata:
+  name: hello
+";
+
+        let report = validate_offline(content, None, None).unwrap();
+        assert_eq!(report.errors, vec![
+            "Service nginx: spec.ports[0].protocol: unsupported value `HTTP`, expected one of: TCP, UDP, SCTP"
+        ]);
+        assert_eq!(report.warnings.len(), 1);
+    }
+}
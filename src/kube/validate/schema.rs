@@ -0,0 +1,427 @@
+use serde_json::{Value, Map};
+use crate::kube::helper::error::{
+    KubeError,
+    validate::Error
+};
+
+// Constant
+const DEFINITIONS_KEY: &str = "definitions";
+const DEFINITIONS_REF_PREFIX: &str = "#/definitions/";
+const GVK_EXTENSION: &str = "x-kubernetes-group-version-kind";
+const INT_OR_STRING_EXTENSION: &str = "x-kubernetes-int-or-string";
+const PRESERVE_UNKNOWN_EXTENSION: &str = "x-kubernetes-preserve-unknown-fields";
+const INT_OR_STRING_FORMAT: &str = "int-or-string";
+const API_VERSION_SEPARATOR: &str = "/";
+
+/// OpenApiSchema
+///
+/// # Description
+/// A Kubernetes OpenAPI v2 schema loaded from a local file. The file could be downloaded once
+/// for a given Kubernetes version (i.e: kubectl get --raw /openapi/v2 > schema.json)
+pub struct OpenApiSchema {
+    definitions: Map<String, Value>
+}
+
+impl OpenApiSchema {
+    /// New
+    ///
+    /// # Description
+    /// Load an OpenAPI schema from a JSON or YAML string
+    ///
+    /// # Arguments
+    /// * `content` - &str
+    ///
+    /// # Return
+    /// Result<Self, KubeError>
+    pub fn new(content: &str) -> Result<Self, KubeError> {
+        let schema: Value = serde_yaml::from_str(content)?;
+        let definitions = schema.get(DEFINITIONS_KEY)
+            .and_then(|d| d.as_object())
+            .ok_or_else(|| KubeError::from(Error::MissingDefinitions))?;
+
+        Ok(OpenApiSchema {
+            definitions: definitions.to_owned()
+        })
+    }
+
+    /// Get Definition
+    ///
+    /// # Description
+    /// Retrieve the definition matching the apiVersion & kind of a Kubernetes object
+    ///
+    /// # Arguments
+    /// * `api_version` - &str
+    /// * `kind` - &str
+    ///
+    /// # Return
+    /// Option<&Value>
+    fn get_definition(&self, api_version: &str, kind: &str) -> Option<&Value> {
+        let (group, version) = match api_version.split_once(API_VERSION_SEPARATOR) {
+            Some((group, version)) => (group, version),
+            None => ("", api_version)
+        };
+
+        self.definitions.values().find(|definition| {
+            definition.get(GVK_EXTENSION)
+                .and_then(|gvk| gvk.as_array())
+                .map(|gvk| gvk.iter().any(|item| {
+                    item.get("group").and_then(|v| v.as_str()) == Some(group)
+                        && item.get("version").and_then(|v| v.as_str()) == Some(version)
+                        && item.get("kind").and_then(|v| v.as_str()) == Some(kind)
+                }))
+                .unwrap_or(false)
+        })
+    }
+
+    /// Validate
+    ///
+    /// # Description
+    /// Validate a Kubernetes object with the definition of it's kind
+    ///
+    /// # Arguments
+    /// * `value` - &Value
+    /// * `api_version` - &str
+    /// * `kind` - &str
+    ///
+    /// # Return
+    /// Option<Vec<String>> None if the schema does not contain the kind
+    pub fn validate(&self, value: &Value, api_version: &str, kind: &str) -> Option<Vec<String>> {
+        let definition = self.get_definition(api_version, kind)?;
+        let validator = Validator::new(Some(&self.definitions));
+
+        let mut errors = Vec::new();
+        validator.validate(value, definition, "", &mut errors);
+
+        Some(errors)
+    }
+}
+
+/// Validator
+///
+/// # Description
+/// Validate a JSON value against a subset of the OpenAPI schema used by Kubernetes
+///     - type
+///     - required
+///     - properties & additionalProperties (unknown fields)
+///     - items
+///     - enum
+///     - $ref (OpenAPI v2 definitions)
+pub struct Validator<'a> {
+    definitions: Option<&'a Map<String, Value>>
+}
+
+impl<'a> Validator<'a> {
+    /// New
+    ///
+    /// # Arguments
+    /// * `definitions` - Option<&'a Map<String, Value>>
+    ///
+    /// # Return
+    /// Self
+    pub fn new(definitions: Option<&'a Map<String, Value>>) -> Self {
+        Validator { definitions }
+    }
+
+    /// Resolve
+    ///
+    /// # Description
+    /// Follow the $ref of a schema to the targeted definition
+    ///
+    /// # Arguments
+    /// * `schema` - &'b Value
+    ///
+    /// # Return
+    /// Option<&'b Value>
+    fn resolve<'b>(&'b self, schema: &'b Value) -> Option<&'b Value> {
+        let reference = match schema.get("$ref").and_then(|r| r.as_str()) {
+            Some(r) => r,
+            None => return Some(schema)
+        };
+
+        let name = reference.strip_prefix(DEFINITIONS_REF_PREFIX)?;
+        self.definitions?.get(name)
+    }
+
+    /// Validate
+    ///
+    /// # Description
+    /// Validate a value against a schema. Errors are pushed with the path of the value
+    ///
+    /// # Arguments
+    /// * `value` - &Value
+    /// * `schema` - &Value
+    /// * `path` - &str
+    /// * `errors` - &mut Vec<String>
+    pub fn validate(&self, value: &Value, schema: &Value, path: &str, errors: &mut Vec<String>) {
+        // an unresolvable reference can't be checked
+        let schema = match self.resolve(schema) {
+            Some(s) => s,
+            None => return
+        };
+
+        if value.is_null() {
+            return;
+        }
+
+        let int_or_string = schema.get(INT_OR_STRING_EXTENSION).and_then(|v| v.as_bool()).unwrap_or(false)
+            || schema.get("format").and_then(|v| v.as_str()) == Some(INT_OR_STRING_FORMAT);
+
+        if int_or_string {
+            if !value.is_i64() && !value.is_u64() && !value.is_string() {
+                push_error(errors, path, Error::WrongType("integer or string".to_owned(), get_type_name(value).to_owned()));
+            }
+
+            return;
+        }
+
+        if let Some(expected) = schema.get("type").and_then(|v| v.as_str()) {
+            if !is_type(value, expected) {
+                push_error(errors, path, Error::WrongType(expected.to_owned(), get_type_name(value).to_owned()));
+                return;
+            }
+        }
+
+        if let Some(allowed) = schema.get("enum").and_then(|v| v.as_array()) {
+            if !allowed.contains(value) {
+                let values = allowed.iter()
+                    .map(|v| v.as_str().map(String::from).unwrap_or_else(|| v.to_string()))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                push_error(errors, path, Error::UnsupportedValue(get_display_value(value), values));
+            }
+        }
+
+        match value {
+            Value::Object(map) => self.validate_object(map, schema, path, errors),
+            Value::Array(items) => {
+                if let Some(item_schema) = schema.get("items") {
+                    for (idx, item) in items.iter().enumerate() {
+                        self.validate(item, item_schema, &format!("{}[{}]", path, idx), errors);
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+
+    /// Validate Object
+    ///
+    /// # Description
+    /// Check the required fields, the unknown fields and validate each properties of an object
+    ///
+    /// # Arguments
+    /// * `map` - &Map<String, Value>
+    /// * `schema` - &Value
+    /// * `path` - &str
+    /// * `errors` - &mut Vec<String>
+    fn validate_object(&self, map: &Map<String, Value>, schema: &Value, path: &str, errors: &mut Vec<String>) {
+        if let Some(required) = schema.get("required").and_then(|v| v.as_array()) {
+            for field in required.iter().filter_map(|f| f.as_str()) {
+                if !map.contains_key(field) {
+                    push_error(errors, path, Error::MissingRequired(field.to_owned()));
+                }
+            }
+        }
+
+        let preserve_unknown = schema.get(PRESERVE_UNKNOWN_EXTENSION)
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+        let properties = schema.get("properties").and_then(|v| v.as_object());
+        let additional = schema.get("additionalProperties");
+
+        for (key, item) in map {
+            let item_path = join_path(path, key);
+            if let Some(property) = properties.and_then(|p| p.get(key)) {
+                self.validate(item, property, &item_path, errors);
+                continue;
+            }
+
+            match additional {
+                Some(Value::Bool(true)) => {},
+                Some(Value::Bool(false)) => push_error(errors, &item_path, Error::UnknownField),
+                Some(additional_schema) => self.validate(item, additional_schema, &item_path, errors),
+                // an object without any properties definition accept anything
+                None => if properties.is_some() && !preserve_unknown {
+                    push_error(errors, &item_path, Error::UnknownField);
+                }
+            }
+        }
+    }
+}
+
+/// Join Path
+///
+/// # Description
+/// Append a key to a dotted path (i.e: spec.template)
+///
+/// # Arguments
+/// * `path` - &str
+/// * `key` - &str
+///
+/// # Return
+/// String
+pub fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        return key.to_owned();
+    }
+
+    format!("{}.{}", path, key)
+}
+
+/// Push Error
+///
+/// # Arguments
+/// * `errors` - &mut Vec<String>
+/// * `path` - &str
+/// * `err` - Error
+pub fn push_error(errors: &mut Vec<String>, path: &str, err: Error) {
+    if path.is_empty() {
+        errors.push(err.to_string());
+    } else {
+        errors.push(format!("{}: {}", path, err));
+    }
+}
+
+/// Get Display Value
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// String
+pub fn get_display_value(value: &Value) -> String {
+    value.as_str()
+        .map(String::from)
+        .unwrap_or_else(|| value.to_string())
+}
+
+/// Is Type
+///
+/// # Arguments
+/// * `value` - &Value
+/// * `expected` - &str
+///
+/// # Return
+/// bool
+fn is_type(value: &Value, expected: &str) -> bool {
+    match expected {
+        "object" => value.is_object(),
+        "array" => value.is_array(),
+        "string" => value.is_string(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "number" => value.is_number(),
+        "boolean" => value.is_boolean(),
+        _ => true
+    }
+}
+
+/// Get Type Name
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// &str
+fn get_type_name(value: &Value) -> &str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) => if n.is_f64() { "number" } else { "integer" },
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    fn get_schema() -> OpenApiSchema {
+        let content = json!({
+            "definitions": {
+                "io.k8s.api.core.v1.Service": {
+                    "type": "object",
+                    "required": ["spec"],
+                    "properties": {
+                        "apiVersion": { "type": "string" },
+                        "kind": { "type": "string" },
+                        "metadata": { "type": "object" },
+                        "spec": { "$ref": "#/definitions/io.k8s.api.core.v1.ServiceSpec" }
+                    },
+                    "x-kubernetes-group-version-kind": [
+                        { "group": "", "kind": "Service", "version": "v1" }
+                    ]
+                },
+                "io.k8s.api.core.v1.ServiceSpec": {
+                    "type": "object",
+                    "properties": {
+                        "type": { "type": "string", "enum": ["ClusterIP", "NodePort"] },
+                        "ports": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "required": ["port"],
+                                "properties": {
+                                    "port": { "type": "integer" },
+                                    "targetPort": { "type": "string", "format": "int-or-string" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        OpenApiSchema::new(&content.to_string()).unwrap()
+    }
+
+    #[test]
+    fn expect_to_validate_service() {
+        let value = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "type": "NodePort",
+                "ports": [{ "port": 80, "targetPort": "http" }]
+            }
+        });
+
+        let errors = get_schema().validate(&value, "v1", "Service");
+        assert!(errors.is_some());
+        assert!(errors.unwrap().is_empty());
+    }
+
+    #[test]
+    fn expect_to_return_errors() {
+        let value = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "type": "Foo",
+                "ports": [{ "targetPort": 80, "name": 10 }],
+                "selectors": { "name": "nginx" }
+            }
+        });
+
+        let errors = get_schema().validate(&value, "v1", "Service").unwrap();
+        assert_eq!(errors.len(), 4);
+        assert!(errors.contains(&"spec.ports[0]: missing required field `port`".to_owned()));
+        assert!(errors.contains(&"spec.ports[0].name: unknown field".to_owned()));
+        assert!(errors.contains(&"spec.selectors: unknown field".to_owned()));
+        assert!(errors.contains(&"spec.type: unsupported value `Foo`, expected one of: ClusterIP, NodePort".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_not_find_definition() {
+        let value = json!({ "apiVersion": "apps/v1", "kind": "Deployment" });
+        let errors = get_schema().validate(&value, "apps/v1", "Deployment");
+
+        assert!(errors.is_none());
+    }
+}
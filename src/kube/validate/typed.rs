@@ -0,0 +1,323 @@
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use k8s_openapi::api::apps::v1::{Deployment, StatefulSet, DaemonSet};
+use k8s_openapi::api::core::v1::{
+    Pod,
+    Service,
+    ConfigMap,
+    Secret,
+    ServiceAccount,
+    Namespace,
+    ResourceQuota,
+    LimitRange,
+    PersistentVolumeClaim
+};
+use k8s_openapi::api::storage::v1::StorageClass;
+use k8s_openapi::api::networking::v1::{Ingress, NetworkPolicy};
+use k8s_openapi::api::rbac::v1::{Role, ClusterRole, RoleBinding, ClusterRoleBinding};
+use crate::kube::scaling::HorizontalPodAutoscaler;
+use crate::kube::policy::PodDisruptionBudget;
+use crate::kube::helper::error::validate::Error;
+use super::schema::{join_path, push_error};
+
+// Constant
+const ROOT_PATH: &str = ".";
+// Fields rendered by maomao which are not available in the k8s_openapi version used
+// with the parent keys which could hold them
+const EXTENDED_FIELDS: [(&str, &[&str]); 2] = [
+    ("grpc", &["livenessProbe", "readinessProbe", "startupProbe"]),
+    ("sleep", &["postStart", "preStop"])
+];
+
+// Check the value with the k8s_openapi struct of a kind
+type Check = fn(&Value) -> Vec<String>;
+
+// Built-in kinds which could be checked with the k8s_openapi structs
+const KINDS: [(&str, &str, Check); 21] = [
+    ("apps/v1", "Deployment", check::<Deployment>),
+    ("apps/v1", "StatefulSet", check::<StatefulSet>),
+    ("apps/v1", "DaemonSet", check::<DaemonSet>),
+    ("v1", "Pod", check::<Pod>),
+    ("v1", "Service", check::<Service>),
+    ("v1", "ConfigMap", check::<ConfigMap>),
+    ("v1", "Secret", check::<Secret>),
+    ("networking.k8s.io/v1", "Ingress", check::<Ingress>),
+    ("networking.k8s.io/v1", "NetworkPolicy", check::<NetworkPolicy>),
+    ("autoscaling/v2", "HorizontalPodAutoscaler", check::<HorizontalPodAutoscaler>),
+    ("policy/v1", "PodDisruptionBudget", check::<PodDisruptionBudget>),
+    ("v1", "ServiceAccount", check::<ServiceAccount>),
+    ("v1", "Namespace", check::<Namespace>),
+    ("v1", "ResourceQuota", check::<ResourceQuota>),
+    ("v1", "LimitRange", check::<LimitRange>),
+    ("v1", "PersistentVolumeClaim", check::<PersistentVolumeClaim>),
+    ("storage.k8s.io/v1", "StorageClass", check::<StorageClass>),
+    ("rbac.authorization.k8s.io/v1", "Role", check::<Role>),
+    ("rbac.authorization.k8s.io/v1", "ClusterRole", check::<ClusterRole>),
+    ("rbac.authorization.k8s.io/v1", "RoleBinding", check::<RoleBinding>),
+    ("rbac.authorization.k8s.io/v1", "ClusterRoleBinding", check::<ClusterRoleBinding>)
+];
+
+/// Is Builtin
+///
+/// # Description
+/// Check whenever the apiVersion & kind match a built-in kind
+///
+/// # Arguments
+/// * `api_version` - &str
+/// * `kind` - &str
+///
+/// # Return
+/// bool
+pub fn is_builtin(api_version: &str, kind: &str) -> bool {
+    KINDS.iter().any(|(v, k, _)| *v == api_version && *k == kind)
+}
+
+/// Validate
+///
+/// # Description
+/// Validate a Kubernetes object by deserializing it with the k8s_openapi struct of it's kind.
+///
+/// # Arguments
+/// * `value` - &Value
+/// * `api_version` - &str
+/// * `kind` - &str
+///
+/// # Return
+/// Option<Vec<String>> None if the kind isn't a built-in kind
+pub fn validate(value: &Value, api_version: &str, kind: &str) -> Option<Vec<String>> {
+    KINDS.iter()
+        .find(|(v, k, _)| *v == api_version && *k == kind)
+        .map(|(_, _, check)| check(value))
+}
+
+/// Check
+///
+/// # Description
+/// Deserialize the value in the targeted type. Missing required fields & wrong types are
+/// reported by the deserializer. Unknown fields are ignored by k8s_openapi, so they're
+/// detected by comparing the value with the serialized struct
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// Vec<String>
+fn check<T: DeserializeOwned + Serialize>(value: &Value) -> Vec<String> {
+    let mut errors = Vec::new();
+    let object: T = match serde_path_to_error::deserialize(value.clone()) {
+        Ok(o) => o,
+        Err(err) => {
+            let path = err.path().to_string();
+            let path = if path == ROOT_PATH { String::new() } else { path };
+            let message = err.into_inner().to_string();
+            if path.is_empty() {
+                errors.push(message);
+            } else {
+                errors.push(format!("{}: {}", path, message));
+            }
+
+            return errors;
+        }
+    };
+
+    match serde_json::to_value(&object) {
+        Ok(serialized) => get_unknown_fields(value, &serialized, "", &mut errors),
+        Err(err) => errors.push(err.to_string())
+    }
+
+    errors
+}
+
+/// Get Unknown Fields
+///
+/// # Description
+/// Push an error for every field of the original value which has been dropped by the deserializer.
+/// Empty fields are skipped as k8s_openapi does not serialize them. The extended fields are skipped too
+///
+/// # Arguments
+/// * `original` - &Value
+/// * `serialized` - &Value
+/// * `path` - &str
+/// * `errors` - &mut Vec<String>
+fn get_unknown_fields(original: &Value, serialized: &Value, path: &str, errors: &mut Vec<String>) {
+    match (original, serialized) {
+        (Value::Object(original), Value::Object(serialized)) => {
+            for (key, item) in original {
+                let item_path = join_path(path, key);
+                match serialized.get(key) {
+                    Some(s) => get_unknown_fields(item, s, &item_path, errors),
+                    None => if !is_empty(item) && !is_extended_field(&item_path) {
+                        push_error(errors, &item_path, Error::UnknownField);
+                    }
+                }
+            }
+        },
+        (Value::Array(original), Value::Array(serialized)) => {
+            for (idx, (item, s)) in original.iter().zip(serialized.iter()).enumerate() {
+                get_unknown_fields(item, s, &format!("{}[{}]", path, idx), errors);
+            }
+        },
+        _ => {}
+    }
+}
+
+/// Is Extended Field
+///
+/// # Description
+/// Check whether the path target a field rendered by maomao which is not available in the k8s_openapi version used
+///     - <containers|initContainers>[*].<livenessProbe|readinessProbe|startupProbe>.grpc
+///     - <containers|initContainers>[*].lifecycle.<postStart|preStop>.sleep
+///
+/// # Arguments
+/// * `path` - &str
+///
+/// # Return
+/// bool
+fn is_extended_field(path: &str) -> bool {
+    let mut keys = path.rsplit('.');
+    let (field, parent) = match (keys.next(), keys.next()) {
+        (Some(field), Some(parent)) => (field, parent),
+        _ => return false
+    };
+
+    let allowed = EXTENDED_FIELDS.iter()
+        .any(|(name, parents)| *name == field && parents.contains(&parent));
+    if !allowed {
+        return false;
+    }
+
+    // the sleep handler is set in the lifecycle of a container
+    let container = match field {
+        "sleep" if keys.next() != Some("lifecycle") => return false,
+        _ => keys.next()
+    };
+
+    container
+        .map(|c| c.starts_with("containers[") || c.starts_with("initContainers["))
+        .unwrap_or_default()
+}
+
+/// Is Empty
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// bool
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Array(items) => items.is_empty(),
+        Value::Object(map) => map.is_empty(),
+        _ => false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use super::*;
+
+    #[test]
+    fn expect_to_validate_deployment() {
+        let value = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "nginx", "labels": {} },
+            "spec": {
+                "selector": { "matchLabels": { "app": "nginx" } },
+                "template": {
+                    "metadata": { "labels": { "app": "nginx" } },
+                    "spec": {
+                        "containers": [{ "name": "nginx", "image": "nginx:1.14.2" }]
+                    }
+                }
+            }
+        });
+
+        let errors = validate(&value, "apps/v1", "Deployment");
+        assert!(errors.is_some());
+        assert!(errors.unwrap().is_empty());
+    }
+
+    #[test]
+    fn expect_to_return_missing_field() {
+        let value = json!({
+            "apiVersion": "apps/v1",
+            "kind": "Deployment",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "selector": { "matchLabels": { "app": "nginx" } },
+                "template": {
+                    "spec": {
+                        "containers": [{ "image": "nginx:1.14.2" }]
+                    }
+                }
+            }
+        });
+
+        let errors = validate(&value, "apps/v1", "Deployment").unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors.first().unwrap().starts_with("spec.template.spec.containers[0]: missing field `name`"));
+    }
+
+    #[test]
+    fn expect_to_return_wrong_type() {
+        let value = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "ports": [{ "port": "http" }]
+            }
+        });
+
+        let errors = validate(&value, "v1", "Service").unwrap();
+        assert_eq!(errors.len(), 1);
+        assert!(errors.first().unwrap().starts_with("spec.ports[0].port: invalid type"));
+    }
+
+    #[test]
+    fn expect_to_return_unknown_field() {
+        let value = json!({
+            "apiVersion": "v1",
+            "kind": "Service",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "ports": [{ "port": 80, "targetPorts": 8080 }]
+            }
+        });
+
+        let errors = validate(&value, "v1", "Service").unwrap();
+        assert_eq!(errors, vec!["spec.ports[0].targetPorts: unknown field"]);
+    }
+
+    #[test]
+    fn expect_to_not_support_custom_kind() {
+        let value = json!({ "apiVersion": "argoproj.io/v1alpha1", "kind": "Workflow" });
+        let errors = validate(&value, "argoproj.io/v1alpha1", "Workflow");
+
+        assert!(errors.is_none());
+    }
+
+    #[test]
+    fn expect_to_only_allow_extended_fields_of_containers() {
+        let value = json!({
+            "apiVersion": "v1",
+            "kind": "Pod",
+            "metadata": { "name": "nginx" },
+            "spec": {
+                "containers": [{
+                    "name": "nginx",
+                    "livenessProbe": { "grpc": { "port": 9090 } },
+                    "lifecycle": { "preStop": { "sleep": { "seconds": 10 } } }
+                }],
+                "grpc": { "port": 9090 },
+                "sleep": 10
+            }
+        });
+
+        let errors = validate(&value, "v1", "Pod").unwrap();
+        assert_eq!(errors, vec!["spec.grpc: unknown field", "spec.sleep: unknown field"]);
+    }
+}
@@ -1,4 +1,4 @@
-use k8s_openapi::api::core::v1::Affinity;
+use k8s_openapi::api::core::v1::{Affinity, PodAntiAffinity};
 use crate::lib::parser::affinity::Affinity as ParserAffinity;
 
 mod node;
@@ -12,6 +12,7 @@ impl AffinityWrapper {
     /// Create an AffinityWrapper which will be used to create:
     ///  - NodeAffinity
     ///  - PodAffinity
+    ///  - PodAntiAffinity
     ///
     /// # Return
     /// Self
@@ -56,4 +57,26 @@ impl AffinityWrapper {
 
         self
     }
+
+    /// Set the Pod Anti Affinity to the wrapper. The terms are the same as the PodAffinity
+    ///
+    /// # Arguments
+    ///
+    /// * `mut self` - Self
+    /// * `affinity` - &ParserAffinity
+    pub fn set_pod_anti_affinity(mut self, affinity: &ParserAffinity) -> Self {
+        if let Some(aff) = affinity.anti.to_owned() {
+            let anti_affinity = pod::PodAffinityWrapper::new()
+                .set_required_aff(&aff)
+                .set_preferred_aff(&aff)
+                .affinity;
+
+            self.affinity.pod_anti_affinity = Some(PodAntiAffinity {
+                required_during_scheduling_ignored_during_execution: anti_affinity.required_during_scheduling_ignored_during_execution,
+                preferred_during_scheduling_ignored_during_execution: anti_affinity.preferred_during_scheduling_ignored_during_execution
+            });
+        }
+
+        self
+    }
 }
\ No newline at end of file
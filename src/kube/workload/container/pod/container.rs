@@ -2,14 +2,18 @@ use std::convert::{Into, From};
 use std::collections::BTreeMap;
 use k8s_openapi::api::core::v1::{
     Container,
+    ContainerPort,
     VolumeMount,
     ResourceRequirements,
-    Probe
+    Probe,
+    Lifecycle,
+    SecurityContext
 };
 use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
 use crate::lib::parser::workload::{
     Container as ParserContainer,
-    resource::Resource as ParserResource
+    resource::Resource as ParserResource,
+    port::Port as ParserPort
 };
 use super::{
     env,
@@ -31,6 +35,9 @@ impl ContainerWrapper {
             name: c.name.to_owned(),
             image: Some(format!("{}:{}", c.image.repo, c.image.tag)),
             image_pull_policy: c.image.policy.to_owned(),
+            command: c.command.to_owned().unwrap_or_default(),
+            args: c.args.to_owned().unwrap_or_default(),
+            working_dir: c.working_dir.to_owned(),
             ..Default::default()
         };
 
@@ -61,6 +68,23 @@ impl ContainerWrapper {
         self
     }
 
+    /// Set container.ports
+    ///
+    /// # Arguments
+    ///
+    /// * `mut self` - Self
+    /// * `c` - &ParserContainer
+    pub fn set_ports(mut self, c: &ParserContainer) -> Self {
+        if let Some(ports) = c.ports.to_owned() {
+            self.container.ports = ports
+                .into_iter()
+                .map(ContainerPort::from)
+                .collect::<Vec<ContainerPort>>();
+        }
+
+        self
+    }
+
     /// Set container.volumeMounts
     ///
     /// # Arguments
@@ -118,18 +142,66 @@ impl ContainerWrapper {
             if let Some(readiness) = probes.readiness {
                 self.container.readiness_probe = Some(Probe::from(readiness));
             }
+
+            if let Some(startup) = probes.startup {
+                self.container.startup_probe = Some(Probe::from(startup));
+            }
+        }
+
+        self
+    }
+
+    /// Set container.lifecycle
+    ///
+    /// # Arguments
+    ///
+    /// * `mut self` - Self
+    /// * `c` - &ParserContainer
+    pub fn set_lifecycle(mut self, c: &ParserContainer) -> Self {
+        self.container.lifecycle = c.lifecycle.to_owned().map(Lifecycle::from);
+        self
+    }
+
+    /// Set container.securityContext
+    ///
+    /// # Arguments
+    ///
+    /// * `mut self` - Self
+    /// * `c` - &ParserContainer
+    pub fn set_security(mut self, c: &ParserContainer) -> Self {
+        if let Some(security) = c.security.to_owned() {
+            self.container.security_context = Some(SecurityContext::from(security));
         }
 
         self
     }
 }
 
+impl From<ParserPort> for ContainerPort {
+    fn from(p: ParserPort) -> ContainerPort {
+        ContainerPort {
+            name: p.name,
+            container_port: p.container_port,
+            protocol: p.protocol,
+            host_port: p.host_port,
+            ..Default::default()
+        }
+    }
+}
+
+// Only the quantities which are set are added. An empty quantity is rejected by the api server
+// and would set a zero limit on a quota or a limitrange
 impl From<ParserResource> for BTreeMap<String, Quantity> {
     fn from(p: ParserResource) -> BTreeMap<String, Quantity> {
         let mut map = BTreeMap::new();
 
-        map.insert("cpu".to_owned(), Quantity(p.cpu.unwrap_or_default()));
-        map.insert("memory".to_owned(), Quantity(p.memory.unwrap_or_default()));
+        if let Some(cpu) = p.cpu {
+            map.insert("cpu".to_owned(), Quantity(cpu));
+        }
+
+        if let Some(memory) = p.memory {
+            map.insert("memory".to_owned(), Quantity(memory));
+        }
 
         map
     }
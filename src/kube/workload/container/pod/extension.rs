@@ -0,0 +1,174 @@
+use serde::Serialize;
+use serde_yaml::{Value, Mapping};
+use crate::lib::parser::Object;
+use crate::lib::parser::workload::{
+    Container as ParserContainer,
+    probes::Probe as ParserProbe,
+    lifecycle::Handler as ParserHandler
+};
+use crate::kube::helper::error::KubeError;
+
+/// To Yaml
+///
+/// # Description
+/// Serialize a workload and set the fields of the containers which are not available
+/// in the k8s_openapi version used by maomao
+///     - grpc action of the probes which require Kubernetes 1.24 or later
+///     - sleep action of the lifecycle hooks which require Kubernetes 1.30 or later
+///       (1.29 with the PodLifecycleSleepAction feature gate)
+///
+/// # Arguments
+/// * `workload` - &T
+/// * `object` - &Object
+///
+/// # Return
+/// Result<String, KubeError>
+pub fn to_yaml<T: Serialize>(workload: &T, object: &Object) -> Result<String, KubeError> {
+    let mut value = serde_yaml::to_value(workload)?;
+    let parser_workload = object.spec.as_ref().and_then(|s| s.workload.as_ref());
+
+    if let (Some(pod_spec), Some(w)) = (get_pod_spec(&mut value), parser_workload) {
+        set_extensions(pod_spec, "containers", &w.containers);
+        set_extensions(pod_spec, "initContainers", &w.init_containers);
+    }
+
+    let yaml = serde_yaml::to_string(&value)?;
+
+    Ok(yaml)
+}
+
+/// Get Pod Spec
+///
+/// # Description
+/// Retrieve the pod spec of a Pod or of the template of a workload
+///
+/// # Arguments
+/// * `value` - &mut Value
+///
+/// # Return
+/// Option<&mut Value>
+fn get_pod_spec(value: &mut Value) -> Option<&mut Value> {
+    let spec = value.get_mut("spec")?;
+    if spec.get("template").is_some() {
+        return spec.get_mut("template")?.get_mut("spec");
+    }
+
+    Some(spec)
+}
+
+/// Set Extensions
+///
+/// # Description
+/// The containers of the pod spec are generated in the same order as the parser containers
+///
+/// # Arguments
+/// * `pod_spec` - &mut Value
+/// * `key` - &str
+/// * `containers` - &[ParserContainer]
+fn set_extensions(pod_spec: &mut Value, key: &str, containers: &[ParserContainer]) {
+    let items = match pod_spec.get_mut(key).and_then(|c| c.as_sequence_mut()) {
+        Some(items) => items,
+        None => return
+    };
+
+    for (item, container) in items.iter_mut().zip(containers.iter()) {
+        if let Some(probes) = &container.probes {
+            set_grpc(item, "livenessProbe", &probes.liveness);
+            set_grpc(item, "readinessProbe", &probes.readiness);
+            set_grpc(item, "startupProbe", &probes.startup);
+        }
+
+        if let Some(lifecycle) = &container.lifecycle {
+            if let Some(hooks) = item.get_mut("lifecycle") {
+                set_sleep(hooks, "postStart", &lifecycle.post_start);
+                set_sleep(hooks, "preStop", &lifecycle.pre_stop);
+            }
+        }
+    }
+}
+
+/// Set Grpc
+///
+/// # Arguments
+/// * `container` - &mut Value
+/// * `key` - &str
+/// * `probe` - &Option<ParserProbe>
+fn set_grpc(container: &mut Value, key: &str, probe: &Option<ParserProbe>) {
+    let grpc = match probe.as_ref().and_then(|p| p.grpc.as_ref()) {
+        Some(grpc) => grpc,
+        None => return
+    };
+
+    let mut action = Mapping::new();
+    action.insert(Value::from("port"), Value::from(grpc.port));
+    if let Some(service) = &grpc.service {
+        action.insert(Value::from("service"), Value::from(service.as_str()));
+    }
+
+    if let Some(Value::Mapping(probe)) = container.get_mut(key) {
+        probe.insert(Value::from("grpc"), Value::Mapping(action));
+    }
+}
+
+/// Set Sleep
+///
+/// # Arguments
+/// * `lifecycle` - &mut Value
+/// * `key` - &str
+/// * `handler` - &Option<ParserHandler>
+fn set_sleep(lifecycle: &mut Value, key: &str, handler: &Option<ParserHandler>) {
+    let seconds = match handler.as_ref().and_then(|h| h.sleep) {
+        Some(seconds) => seconds,
+        None => return
+    };
+
+    let mut action = Mapping::new();
+    action.insert(Value::from("seconds"), Value::from(seconds));
+
+    if let Some(Value::Mapping(hook)) = lifecycle.get_mut(key) {
+        hook.insert(Value::from("sleep"), Value::Mapping(action));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use crate::kube::workload::deployment::get_deployment_from_object;
+
+    #[test]
+    fn expect_to_set_grpc_and_sleep() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                [workload.rusty.probes]
+                    [workload.rusty.probes.liveness]
+                        grpc = { port = 9090, service = "rusty" }
+
+                    [workload.rusty.probes.startup]
+                        http_get = { path = "/health", port = "http" }
+                        failure_thresold = 30
+                        period_seconds = 10
+
+                [workload.rusty.lifecycle]
+                    pre_stop = { sleep = 10 }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let yaml = get_deployment_from_object(&object).unwrap();
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        let container = &value["spec"]["template"]["spec"]["containers"][0];
+
+        assert_eq!(container["livenessProbe"]["grpc"]["port"], 9090);
+        assert_eq!(container["livenessProbe"]["grpc"]["service"], "rusty");
+        assert_eq!(container["startupProbe"]["httpGet"]["port"], "http");
+        assert_eq!(container["startupProbe"]["periodSeconds"], 10);
+        assert_eq!(container["lifecycle"]["preStop"]["sleep"]["seconds"], 10);
+    }
+}
\ No newline at end of file
@@ -1,12 +1,16 @@
 use std::convert::From;
+use std::collections::BTreeMap;
 use k8s_openapi::api::core::v1::{
     PodTemplateSpec,
     PodSpec,
+    PodSecurityContext,
     Container,
     Toleration,
+    TopologySpreadConstraint,
+    Volume,
     VolumeMount
 };
-use k8s_openapi::apimachinery::pkg::apis::meta::v1::ObjectMeta;
+use k8s_openapi::apimachinery::pkg::apis::meta::v1::{ObjectMeta, LabelSelector};
 use crate::lib::parser::{
     Object,
     affinity::Affinity as ParserAffinity
@@ -15,6 +19,9 @@ use crate::lib::parser::workload::{
     Workload,
     Container as ParserContainer,
     toleration::Toleration as ParserToleration,
+    security::Security as ParserSecurity,
+    topology::TopologySpread as ParserTopologySpread,
+    volume::Volume as ParserVolume,
     volume::VolumeMount as ParserVolumeMount
 };
 use crate::kube::workload::affinity::AffinityWrapper;
@@ -23,6 +30,11 @@ mod container;
 mod env;
 mod env_from;
 mod probe;
+mod security;
+pub mod extension;
+
+// Constant
+const DEFAULT_CONTAINER_ANNOTATION: &str = "kubectl.kubernetes.io/default-container";
 
 struct PodSpecWrapper {
     spec: PodSpec
@@ -60,6 +72,25 @@ impl PodSpecWrapper {
         self
     }
 
+    /// Set Init Containers
+    ///
+    /// # Description
+    /// Set the containers which run before the containers of the pod
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `parser_containers` - Vec<ParserContainer>
+    ///
+    /// # Return
+    /// Self
+    fn set_init_containers(mut self, parser_containers: Vec<ParserContainer>) -> Self {
+        self.spec.init_containers = parser_containers.into_iter()
+            .map(Container::from)
+            .collect::<Vec<Container>>();
+
+        self
+    }
+
     /// Set Tolerations
     ///
     /// # Description
@@ -101,13 +132,117 @@ impl PodSpecWrapper {
         if let Some(af) = aff {
             let affinity_wrapper = AffinityWrapper::new()
                 .set_node_affinity(&af)
-                .set_pod_affinity(&af);
+                .set_pod_affinity(&af)
+                .set_pod_anti_affinity(&af);
                 
             self.spec.affinity = Some(affinity_wrapper.affinity);
         }
 
         self
     }
+
+    /// Set Scheduling
+    ///
+    /// # Description
+    /// Set the node selector, the priority class and the termination grace period of the pods
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `workload` - &Workload
+    ///
+    /// # Return
+    /// Self
+    fn set_scheduling(mut self, workload: &Workload) -> Self {
+        self.spec.node_selector = workload.node_selector.to_owned().unwrap_or_default();
+        self.spec.priority_class_name = workload.priority_class.to_owned();
+        self.spec.termination_grace_period_seconds = workload.termination_grace_period_seconds;
+
+        self
+    }
+
+    /// Set Topology Spread
+    ///
+    /// # Description
+    /// Set the topology spread constraints. The selector of the template is used when a constraint
+    /// does not define it's labels
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `constraints` - Option<Vec<ParserTopologySpread>>
+    /// * `selector` - &BTreeMap<String, String>
+    ///
+    /// # Return
+    /// Self
+    fn set_topology_spread(mut self, constraints: Option<Vec<ParserTopologySpread>>, selector: &BTreeMap<String, String>) -> Self {
+        if let Some(constraints) = constraints {
+            self.spec.topology_spread_constraints = constraints.into_iter()
+                .map(|c| TopologySpreadConstraint {
+                    label_selector: Some(LabelSelector {
+                        match_labels: c.labels.unwrap_or_else(|| selector.to_owned()),
+                        ..Default::default()
+                    }),
+                    max_skew: c.max_skew,
+                    topology_key: c.topology_key,
+                    when_unsatisfiable: c.when_unsatisfiable
+                })
+                .collect::<Vec<TopologySpreadConstraint>>();
+        }
+
+        self
+    }
+
+    /// Set Service Account
+    ///
+    /// # Description
+    /// Set the service account used by the pods
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `service_account` - Option<String>
+    ///
+    /// # Return
+    /// Self
+    fn set_service_account(mut self, service_account: Option<String>) -> Self {
+        self.spec.service_account_name = service_account;
+        self
+    }
+
+    /// Set Security
+    ///
+    /// # Description
+    /// Set the security context of the pod
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `security` - Option<ParserSecurity>
+    ///
+    /// # Return
+    /// Self
+    fn set_security(mut self, security: Option<ParserSecurity>) -> Self {
+        self.spec.security_context = security.map(PodSecurityContext::from);
+        self
+    }
+
+    /// Set Volumes
+    ///
+    /// # Description
+    /// Set the volumes which are mounted by the containers
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `parser_volumes` - Option<Vec<ParserVolume>>
+    ///
+    /// # Return
+    /// Self
+    fn set_volumes(mut self, parser_volumes: Option<Vec<ParserVolume>>) -> Self {
+        if let Some(volumes) = parser_volumes {
+            self.spec.volumes = volumes.into_iter()
+                .map(Volume::from)
+                .collect::<Vec<Volume>>();
+        }
+
+        self
+    }
 }
 
 // @Question: Should we make this more flexible ?
@@ -115,9 +250,12 @@ impl From<ParserContainer> for Container {
     fn from(c: ParserContainer) -> Self {
         let cont = container::ContainerWrapper::new(&c)
             .set_env(&c)
+            .set_ports(&c)
             .set_volumes(&c)
             .set_resources(&c)
-            .set_probes(&c);
+            .set_probes(&c)
+            .set_lifecycle(&c)
+            .set_security(&c);
 
         cont.container
     }
@@ -141,6 +279,7 @@ impl From<ParserVolumeMount> for VolumeMount {
             name: t.name.unwrap_or_default(),
             mount_path: t.path.unwrap_or_default(),
             read_only: t.read_only,
+            sub_path: t.sub_path,
             ..Default::default()
         }
     }
@@ -149,7 +288,8 @@ impl From<ParserVolumeMount> for VolumeMount {
 /// Get Pod Template Spec
 ///
 /// # Description
-/// Create a k8s_openapi::api::core::v1::PodTemplateSpec
+/// Create a k8s_openapi::api::core::v1::PodTemplateSpec. The pod_labels & pod_annotations are only set
+/// on the pods. The container which has the `default` flag is set in the kubectl.kubernetes.io/default-container annotation
 ///
 /// # Arguments
 /// * `workload` - Workload
@@ -157,18 +297,32 @@ impl From<ParserVolumeMount> for VolumeMount {
 ///
 /// # Return
 /// k8s_openapi::api::core::v1::PodTemplateSpec
-pub fn get_pod_template_spec(workload: Workload, object: &Object, metadata: ObjectMeta) -> PodTemplateSpec {
-    let mut template = PodTemplateSpec {
-        metadata: Some(metadata),
-        spec: None
-    };
+pub fn get_pod_template_spec(workload: Workload, object: &Object, mut metadata: ObjectMeta) -> PodTemplateSpec {
+    if let Some(labels) = workload.pod_labels.to_owned() {
+        metadata.labels.extend(labels);
+    }
+
+    if let Some(annotations) = workload.pod_annotations.to_owned() {
+        metadata.annotations.extend(annotations);
+    }
+
+    if let Some(container) = workload.containers.iter().find(|c| c.default) {
+        metadata.annotations.insert(DEFAULT_CONTAINER_ANNOTATION.to_owned(), container.name.to_owned());
+    }
 
     let wrapper = PodSpecWrapper::new()
+        .set_scheduling(&workload)
+        .set_topology_spread(workload.topology_spread, &object.selector)
         .set_containers(workload.containers)
+        .set_init_containers(workload.init_containers)
         .set_tolerations(workload.tolerations)
-        .set_affinity(object.affinity.to_owned());
+        .set_affinity(object.affinity.to_owned())
+        .set_service_account(workload.service_account)
+        .set_security(workload.security)
+        .set_volumes(workload.volumes);
 
-    template.spec = Some(wrapper.spec);
-    
-    template
+    PodTemplateSpec {
+        metadata: Some(metadata),
+        spec: Some(wrapper.spec)
+    }
 }
\ No newline at end of file
@@ -4,13 +4,36 @@ use k8s_openapi::api::core::v1::{
     ExecAction,
     TCPSocketAction,
     HTTPGetAction,
-    HTTPHeader
+    HTTPHeader,
+    Handler,
+    Lifecycle
 };
 use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
 use crate::lib::parser::workload::probes::{
     Probe as ParserProbe,
     ProbeHttpGet
 };
+use crate::lib::parser::workload::lifecycle::{
+    Lifecycle as ParserLifecycle,
+    Handler as ParserHandler
+};
+
+/// Get Int Or String
+///
+/// # Description
+/// Convert a port to an IntOrString. A named port is kept as a String
+///
+/// # Arguments
+/// * `port` - String
+///
+/// # Return
+/// IntOrString
+fn get_int_or_string(port: String) -> IntOrString {
+    match port.parse::<i32>() {
+        Ok(number) => IntOrString::Int(number),
+        Err(_) => IntOrString::String(port)
+    }
+}
 
 /// Convert a collection to a TCPSocketAction
 ///
@@ -24,7 +47,7 @@ fn get_socket_from_map(map: BTreeMap<String, String>) -> Option<TCPSocketAction>
     }
 
     if let Some(port) = map.get("port") {
-        socket.port = IntOrString::String(port.to_owned());
+        socket.port = get_int_or_string(port.to_owned());
     }
 
     Some(socket)
@@ -35,6 +58,7 @@ impl From<ParserProbe> for Probe {
         let mut probe = Probe {
             failure_threshold: p.failure_thresold,
             initial_delay_seconds: p.initial_delays_seconds,
+            period_seconds: p.period_seconds,
             success_threshold: p.success_thresold,
             timeout_seconds: p.timeout_seconds,
             ..Default::default()
@@ -73,12 +97,29 @@ impl From<ProbeHttpGet> for HTTPGetAction {
         }
 
         if let Some(port) = p.port {
-            match port.parse::<i32>() {
-                Ok(number) => http.port = IntOrString::Int(number),
-                Err(_) => http.port = IntOrString::String(port)
-            }
+            http.port = get_int_or_string(port);
         }
 
         http
     }
+}
+
+// The sleep action is not available in the k8s_openapi version used. It's set by the extension module
+impl From<ParserHandler> for Handler {
+    fn from(h: ParserHandler) -> Self {
+        Handler {
+            exec: h.exec.map(|command| ExecAction { command }),
+            http_get: h.http_get.map(HTTPGetAction::from),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ParserLifecycle> for Lifecycle {
+    fn from(l: ParserLifecycle) -> Self {
+        Lifecycle {
+            post_start: l.post_start.map(Handler::from),
+            pre_stop: l.pre_stop.map(Handler::from)
+        }
+    }
 }
\ No newline at end of file
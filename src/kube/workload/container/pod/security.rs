@@ -0,0 +1,60 @@
+use std::convert::From;
+use k8s_openapi::api::core::v1::{
+    PodSecurityContext,
+    SecurityContext,
+    Capabilities,
+    SeccompProfile
+};
+use crate::lib::parser::workload::security::{
+    Security as ParserSecurity,
+    Capabilities as ParserCapabilities,
+    SeccompProfile as ParserSeccompProfile
+};
+
+impl From<ParserCapabilities> for Capabilities {
+    fn from(c: ParserCapabilities) -> Self {
+        Capabilities {
+            add: c.add,
+            drop: c.drop
+        }
+    }
+}
+
+impl From<ParserSeccompProfile> for SeccompProfile {
+    fn from(s: ParserSeccompProfile) -> Self {
+        SeccompProfile {
+            type_: s.kind,
+            localhost_profile: s.localhost_profile
+        }
+    }
+}
+
+impl From<ParserSecurity> for PodSecurityContext {
+    fn from(s: ParserSecurity) -> Self {
+        PodSecurityContext {
+            run_as_user: s.run_as_user,
+            run_as_group: s.run_as_group,
+            run_as_non_root: s.run_as_non_root,
+            fs_group: s.fs_group,
+            supplemental_groups: s.supplemental_groups.unwrap_or_default(),
+            seccomp_profile: s.seccomp_profile.map(SeccompProfile::from),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ParserSecurity> for SecurityContext {
+    fn from(s: ParserSecurity) -> Self {
+        SecurityContext {
+            run_as_user: s.run_as_user,
+            run_as_group: s.run_as_group,
+            run_as_non_root: s.run_as_non_root,
+            read_only_root_filesystem: s.read_only_root_filesystem,
+            allow_privilege_escalation: s.allow_privilege_escalation,
+            privileged: s.privileged,
+            capabilities: s.capabilities.map(Capabilities::from),
+            seccomp_profile: s.seccomp_profile.map(SeccompProfile::from),
+            ..Default::default()
+        }
+    }
+}
\ No newline at end of file
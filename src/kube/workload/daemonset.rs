@@ -1,6 +1,7 @@
 use k8s_openapi::api::apps::v1::{
     DaemonSet,
-    DaemonSetSpec
+    DaemonSetSpec,
+    DaemonSetUpdateStrategy
 };
 use crate::lib::parser::Object;
 use crate::kube::common;
@@ -54,9 +55,11 @@ impl DaemonSetWrapper {
 
         if let Some(workload) = parser_spec.workload {
             let spec = DaemonSetSpec {
+                update_strategy: workload.strategy.to_owned().map(DaemonSetUpdateStrategy::from),
+                min_ready_seconds: workload.min_ready_seconds,
+                revision_history_limit: workload.revision_history_limit,
                 selector: common::get_label_selector_from_object(&object),
-                template: pod::get_pod_template_spec(workload, object, metadata),
-                ..Default::default()
+                template: pod::get_pod_template_spec(workload, object, metadata)
             };
 
             self.workload.spec = Some(spec);
@@ -83,7 +86,7 @@ impl DaemonSetWrapper {
 /// Result<String, KubeError>
 pub fn get_daemonset_from_object(object: &Object) -> Result<String, KubeError> {
     let daemonset = DaemonSetWrapper::new(&object).set_spec(&object)?;
-    let daemonset_string = serde_yaml::to_string(&daemonset.workload)?;
+    let daemonset_string = pod::extension::to_yaml(&daemonset.workload, object)?;
 
     Ok(daemonset_string)
 }
@@ -170,4 +173,35 @@ mod tests {
 
         println!("{}", yaml.unwrap());
     }
+
+    #[test]
+    fn expect_to_set_daemonset_strategy() {
+        let template = r#"
+            kind = 'workload::daemonset'
+            name = 'rusty'
+            metadata = { name = 'rusty-elasticsearch', tier = 'monitoring' }
+
+            [workload]
+                min_ready_seconds = 5
+
+                [workload.strategy]
+                    type = 'RollingUpdate'
+                    max_unavailable = '10%'
+
+                [workload.rust]
+                image = 'foo'
+                tag = 'bar'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let daemonset = DaemonSetWrapper::new(&object).set_spec(&object).unwrap();
+        let spec = daemonset.workload.spec.unwrap();
+
+        assert_eq!(spec.min_ready_seconds.unwrap(), 5);
+        let rolling_update = spec.update_strategy.unwrap().rolling_update.unwrap();
+        assert_eq!(
+            rolling_update.max_unavailable.unwrap(),
+            k8s_openapi::apimachinery::pkg::util::intstr::IntOrString::String("10%".to_owned())
+        );
+    }
 }
\ No newline at end of file
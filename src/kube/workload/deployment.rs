@@ -1,6 +1,7 @@
 use k8s_openapi::api::apps::v1::{
     Deployment,
-    DeploymentSpec
+    DeploymentSpec,
+    DeploymentStrategy
 };
 use crate::lib::parser::Object;
 use crate::kube::common;
@@ -56,6 +57,10 @@ impl DeploymentWrapper {
         if let Some(workload) = parser_spec.workload {
             let spec = DeploymentSpec {
                 replicas: workload.replicas,
+                strategy: workload.strategy.to_owned().map(DeploymentStrategy::from),
+                min_ready_seconds: workload.min_ready_seconds,
+                revision_history_limit: workload.revision_history_limit,
+                progress_deadline_seconds: workload.progress_deadline_seconds,
                 selector: common::get_label_selector_from_object(&object),
                 template: pod::get_pod_template_spec(workload, object, metadata),
                 ..Default::default()
@@ -84,13 +89,14 @@ impl DeploymentWrapper {
 /// Result<String, KubeError>
 pub fn get_deployment_from_object(object: &Object) -> Result<String, KubeError> {
     let deployment = DeploymentWrapper::new(object).set_spec(object)?;
-    let deployment_string = serde_yaml::to_string(&deployment.workload)?;
+    let deployment_string = pod::extension::to_yaml(&deployment.workload, object)?;
 
     Ok(deployment_string)
 }
 
 #[cfg(test)]
 mod tests {
+    use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
     use crate::lib::parser::get_parsed_objects;
     use super::*;
 
@@ -105,6 +111,7 @@ mod tests {
 
             [workload]
                 replicas = 3
+                service_account = 'rusty-sa'
 
                 [workload.rust]
                     image = 'foo'
@@ -127,6 +134,7 @@ mod tests {
         assert_eq!(spec_metadata.labels.get("name").unwrap(), "rusty");
 
         let pod_spec = workload_spec.template.spec.unwrap();
+        assert_eq!(pod_spec.service_account_name.to_owned().unwrap(), "rusty-sa");
         let container = pod_spec.containers.get(0);
 
         assert!(container.is_some());
@@ -230,6 +238,266 @@ mod tests {
         assert_eq!(secret.secret_ref.to_owned().unwrap().name.unwrap(), "default_secret");
     }
 
+    #[test]
+    fn expect_to_create_init_containers() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.migrate]
+                    image = 'rusty-migration'
+                    tag = '1.0.0'
+                    init = true
+
+                    [workload.migrate.resources]
+                        limits = { memory = '64Mi' }
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let pod_spec = deployment.workload.spec.unwrap().template.spec.unwrap();
+
+        assert_eq!(pod_spec.containers.len(), 1);
+        assert_eq!(pod_spec.containers.first().unwrap().name, "rusty");
+
+        let migrate = pod_spec.init_containers.first().unwrap();
+        assert_eq!(migrate.name, "migrate");
+        assert_eq!(migrate.image.as_ref().unwrap(), "rusty-migration:1.0.0");
+        assert!(migrate.resources.is_some());
+    }
+
+    #[test]
+    fn expect_to_keep_container_order() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+                    default = true
+
+                [workload.proxy]
+                    image = 'envoy'
+                    tag = '1.18.3'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let template = deployment.workload.spec.unwrap().template;
+
+        let names = template.spec.unwrap().containers
+            .into_iter()
+            .map(|c| c.name)
+            .collect::<Vec<String>>();
+        assert_eq!(names, vec!["rusty", "proxy"]);
+
+        let annotations = template.metadata.unwrap().annotations;
+        assert_eq!(annotations.get("kubectl.kubernetes.io/default-container").unwrap(), "rusty");
+    }
+
+    #[test]
+    fn expect_to_set_command_and_ports() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+                    command = ['/bin/rusty']
+                    args = ['serve']
+                    working_dir = '/app'
+                    ports = [
+                        { name = 'http', container_port = 8080, protocol = 'TCP' }
+                    ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let pod_spec = deployment.workload.spec.unwrap().template.spec.unwrap();
+        let container = pod_spec.containers.first().unwrap();
+
+        assert_eq!(container.command, vec!["/bin/rusty"]);
+        assert_eq!(container.args, vec!["serve"]);
+        assert_eq!(container.working_dir.as_ref().unwrap(), "/app");
+
+        let port = container.ports.first().unwrap();
+        assert_eq!(port.name.as_ref().unwrap(), "http");
+        assert_eq!(port.container_port, 8080);
+        assert_eq!(port.protocol.as_ref().unwrap(), "TCP");
+    }
+
+    #[test]
+    fn expect_to_set_security_context() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.security]
+                    restricted = true
+                    fs_group = 2000
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.security]
+                        read_only_root_filesystem = true
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let pod_spec = deployment.workload.spec.unwrap().template.spec.unwrap();
+
+        let pod_security = pod_spec.security_context.unwrap();
+        assert!(pod_security.run_as_non_root.unwrap());
+        assert_eq!(pod_security.fs_group.unwrap(), 2000);
+        assert_eq!(pod_security.seccomp_profile.unwrap().type_, "RuntimeDefault");
+
+        let security = pod_spec.containers.first().unwrap().security_context.to_owned().unwrap();
+        assert!(security.read_only_root_filesystem.unwrap());
+        assert!(!security.allow_privilege_escalation.unwrap());
+        assert_eq!(security.capabilities.unwrap().drop, vec!["ALL"]);
+    }
+
+    #[test]
+    fn expect_to_set_scheduling() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                node_selector = { "kubernetes.io/os" = "linux" }
+                priority_class = 'high-priority'
+                termination_grace_period_seconds = 60
+                pod_labels = { track = 'stable' }
+                topology_spread = [
+                    { max_skew = 1, topology_key = 'topology.kubernetes.io/zone' }
+                ]
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+            [affinity]
+                [affinity.anti]
+                    [affinity.anti.preferred]
+                        [affinity.anti.preferred.hostname]
+                            weight = 100
+                            topology = 'kubernetes.io/hostname'
+                            expressions = [
+                                { key = 'name', operator = 'In', values = ['rusty'] }
+                            ]
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let pod_spec = deployment.workload.spec.unwrap().template.spec.unwrap();
+
+        assert_eq!(pod_spec.node_selector.get("kubernetes.io/os").unwrap(), "linux");
+        assert_eq!(pod_spec.priority_class_name.unwrap(), "high-priority");
+        assert_eq!(pod_spec.termination_grace_period_seconds.unwrap(), 60);
+
+        let spread = pod_spec.topology_spread_constraints.first().unwrap();
+        assert_eq!(spread.topology_key, "topology.kubernetes.io/zone");
+        assert_eq!(spread.when_unsatisfiable, "DoNotSchedule");
+        // the selector of the template is used instead of the labels of the pods
+        let labels = spread.label_selector.to_owned().unwrap().match_labels;
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels.get("name").unwrap(), "rusty");
+
+        let anti = pod_spec.affinity.unwrap().pod_anti_affinity.unwrap();
+        let term = anti.preferred_during_scheduling_ignored_during_execution.first().unwrap();
+        assert_eq!(term.weight, 100);
+        assert_eq!(term.pod_affinity_term.topology_key, "kubernetes.io/hostname");
+    }
+
+    #[test]
+    fn expect_to_set_rollout_strategy() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                replicas = 3
+                min_ready_seconds = 10
+                revision_history_limit = 5
+                progress_deadline_seconds = 600
+
+                [workload.strategy]
+                    type = 'RollingUpdate'
+                    max_surge = '25%'
+                    max_unavailable = 0
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let spec = deployment.workload.spec.unwrap();
+
+        assert_eq!(spec.min_ready_seconds.unwrap(), 10);
+        assert_eq!(spec.revision_history_limit.unwrap(), 5);
+        assert_eq!(spec.progress_deadline_seconds.unwrap(), 600);
+
+        let strategy = spec.strategy.unwrap();
+        assert_eq!(strategy.type_.unwrap(), "RollingUpdate");
+
+        let rolling_update = strategy.rolling_update.unwrap();
+        assert_eq!(rolling_update.max_surge.unwrap(), IntOrString::String("25%".to_owned()));
+        assert_eq!(rolling_update.max_unavailable.unwrap(), IntOrString::Int(0));
+    }
+
+    #[test]
+    fn expect_to_separate_selector_labels() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend', version = 'v2' }
+            annotations = { owner = 'team-rust' }
+
+            [workload]
+                pod_labels = { track = 'stable' }
+                pod_annotations = { "prometheus.io/scrape" = "true" }
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap().workload;
+        assert_eq!(deployment.metadata.labels.get("version").unwrap(), "v2");
+        assert!(!deployment.metadata.labels.contains_key("track"));
+
+        let spec = deployment.spec.unwrap();
+        assert_eq!(spec.selector.match_labels.len(), 1);
+        assert_eq!(spec.selector.match_labels.get("name").unwrap(), "rusty");
+
+        let pod_metadata = spec.template.metadata.unwrap();
+        assert_eq!(pod_metadata.labels.get("version").unwrap(), "v2");
+        assert_eq!(pod_metadata.labels.get("track").unwrap(), "stable");
+        assert_eq!(pod_metadata.annotations.get("prometheus.io/scrape").unwrap(), "true");
+        assert!(!pod_metadata.annotations.contains_key("owner"));
+    }
+
     #[test]
     fn expect_to_generate_yaml() {
         let template = r#"
@@ -314,4 +582,33 @@ mod tests {
         let preferred = node_affinity.preferred_during_scheduling_ignored_during_execution;
         assert!(!preferred.is_empty());
     }
+
+    #[test]
+    fn expect_to_only_set_defined_resources() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.resources]
+                        limits = { memory = '64Mi' }
+                        requests = { cpu = '100m', memory = '32Mi' }
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let deployment = DeploymentWrapper::new(&object).set_spec(&object).unwrap();
+        let pod_spec = deployment.workload.spec.unwrap().template.spec.unwrap();
+        let resources = pod_spec.containers.first().unwrap().resources.to_owned().unwrap();
+
+        assert_eq!(resources.limits.len(), 1);
+        assert_eq!(resources.limits.get("memory").unwrap().0, "64Mi");
+        assert!(!resources.limits.contains_key("cpu"));
+        assert_eq!(resources.requests.get("cpu").unwrap().0, "100m");
+        assert_eq!(resources.requests.get("memory").unwrap().0, "32Mi");
+    }
 }
\ No newline at end of file
@@ -3,8 +3,9 @@ use crate::kube::helper::error::KubeError;
 
 // utils to construct workloads
 mod container;
-mod volumes;
+pub mod volumes;
 mod affinity;
+mod strategy;
 
 // workload
 mod deployment;
@@ -44,6 +44,7 @@ impl PodWrapper {
                 object,
                 self.workload.metadata.to_owned()
             );
+            self.workload.metadata = template_spec.metadata.unwrap_or_default();
             self.workload.spec = template_spec.spec;
         }
 
@@ -58,7 +59,7 @@ impl PodWrapper {
 /// * `object` - &Object
 pub fn get_pod_from_object(object: &Object) -> Result<String, KubeError> {
     let po = PodWrapper::new(object).set_spec(object)?;
-    let po_string = serde_yaml::to_string(&po.workload)?;
+    let po_string = pod::extension::to_yaml(&po.workload, object)?;
 
     Ok(po_string)
 }
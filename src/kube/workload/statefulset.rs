@@ -1,6 +1,7 @@
 use k8s_openapi::api::apps::v1::{
     StatefulSet,
-    StatefulSetSpec
+    StatefulSetSpec,
+    StatefulSetUpdateStrategy
 };
 use crate::lib::parser::Object;
 use crate::kube::common;
@@ -50,16 +51,25 @@ impl StatefulSetWrapper {
     /// # Return
     /// Result<Self, KubeError>
     fn set_spec(mut self, object: &Object) -> Result<Self, KubeError> {
-        let metadata = common::get_metadata_from_object(&object);
+        let metadata = common::get_workload_metadata_from_object(&object);
         let parser_spec = object.spec.to_owned()
             .ok_or_else(|| KubeError::from(Error::MissingSpec))?;
 
         if let Some(workload) = parser_spec.workload {
+            // the service_name is required. The name of the StatefulSet is used by default
+            let service_name = workload.service_name.to_owned()
+                .or_else(|| self.workload.metadata.name.to_owned())
+                .unwrap_or_default();
+
             let spec = StatefulSetSpec {
+                replicas: workload.replicas,
+                service_name,
+                pod_management_policy: workload.pod_management_policy.to_owned(),
+                update_strategy: workload.strategy.to_owned().map(StatefulSetUpdateStrategy::from),
+                revision_history_limit: workload.revision_history_limit,
                 selector: common::get_label_selector_from_object(&object),
                 template: pod::get_pod_template_spec(workload, object, metadata),
-                volume_claim_templates: claim::get_pvc_list(&object).unwrap_or_default(),
-                ..Default::default()
+                volume_claim_templates: claim::get_pvc_list(&object).unwrap_or_default()
             };
     
             self.workload.spec = Some(spec);    
@@ -85,7 +95,7 @@ impl StatefulSetWrapper {
 /// Result<String, KubeError>
 pub fn get_statefulset_from_object(object: &Object) -> Result<String, KubeError> {
     let statefulset = StatefulSetWrapper::new(&object).set_spec(&object)?;
-    let statefulset_string = serde_yaml::to_string(&statefulset.workload)?;
+    let statefulset_string = pod::extension::to_yaml(&statefulset.workload, object)?;
 
     Ok(statefulset_string)
 }
@@ -186,4 +196,37 @@ mod tests {
         let statefulset = super::get_statefulset_from_object(&object);
         assert!(statefulset.is_ok());
     }
+
+    #[test]
+    fn expect_to_set_statefulset_strategy() {
+        let template = r#"
+            kind = "workload::statefulset"
+            name = "rusty"
+            metadata = { name = "rusty", tier = "backend" }
+
+            [workload]
+                replicas = 3
+                pod_management_policy = "Parallel"
+
+                [workload.strategy]
+                    type = "RollingUpdate"
+                    partition = 2
+
+                [workload.rust]
+                image = "foo"
+                tag = "bar"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let statefulset = StatefulSetWrapper::new(&object).set_spec(&object).unwrap();
+        let spec = statefulset.workload.spec.unwrap();
+
+        assert_eq!(spec.replicas.unwrap(), 3);
+        assert_eq!(spec.service_name, "rusty");
+        assert_eq!(spec.pod_management_policy.unwrap(), "Parallel");
+
+        let strategy = spec.update_strategy.unwrap();
+        assert_eq!(strategy.type_.unwrap(), "RollingUpdate");
+        assert_eq!(strategy.rolling_update.unwrap().partition.unwrap(), 2);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::convert::From;
+use k8s_openapi::api::apps::v1::{
+    DeploymentStrategy,
+    RollingUpdateDeployment,
+    StatefulSetUpdateStrategy,
+    RollingUpdateStatefulSetStrategy,
+    DaemonSetUpdateStrategy,
+    RollingUpdateDaemonSet
+};
+use k8s_openapi::apimachinery::pkg::util::intstr::IntOrString;
+use crate::lib::parser::workload::strategy::Strategy;
+
+impl From<Strategy> for DeploymentStrategy {
+    fn from(s: Strategy) -> Self {
+        let mut strategy = DeploymentStrategy {
+            type_: s.kind,
+            rolling_update: None
+        };
+
+        if s.max_surge.is_some() || s.max_unavailable.is_some() {
+            strategy.rolling_update = Some(RollingUpdateDeployment {
+                max_surge: s.max_surge.map(IntOrString::from),
+                max_unavailable: s.max_unavailable.map(IntOrString::from)
+            });
+        }
+
+        strategy
+    }
+}
+
+impl From<Strategy> for StatefulSetUpdateStrategy {
+    fn from(s: Strategy) -> Self {
+        StatefulSetUpdateStrategy {
+            type_: s.kind,
+            rolling_update: s.partition.map(|partition| RollingUpdateStatefulSetStrategy {
+                partition: Some(partition)
+            })
+        }
+    }
+}
+
+impl From<Strategy> for DaemonSetUpdateStrategy {
+    fn from(s: Strategy) -> Self {
+        DaemonSetUpdateStrategy {
+            type_: s.kind,
+            rolling_update: s.max_unavailable.map(|max| RollingUpdateDaemonSet {
+                max_unavailable: Some(IntOrString::from(max))
+            })
+        }
+    }
+}
\ No newline at end of file
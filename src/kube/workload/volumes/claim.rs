@@ -97,7 +97,7 @@ impl PvcWrapper {
 fn get_typed_local_object_reference(m: Option<DataSource>) -> Option<TypedLocalObjectReference> {
     if let Some(data_source) = m {
         return Some(TypedLocalObjectReference {
-            api_group: None,
+            api_group: data_source.api_group,
             kind: data_source.kind.unwrap_or_default(),
             name: data_source.name.unwrap_or_default()
         });
@@ -106,6 +106,20 @@ fn get_typed_local_object_reference(m: Option<DataSource>) -> Option<TypedLocalO
     None
 }
 
+/// Get Pvc
+///
+/// # Description
+/// Create a PersistentVolumeClaim from a parser volume claim
+///
+/// # Arguments
+/// * `parser_pvc` - &parser::volume::VolumeClaimTemplates
+///
+/// # Return
+/// PersistentVolumeClaim
+pub fn get_pvc(parser_pvc: &parser::volume::VolumeClaimTemplates) -> PersistentVolumeClaim {
+    PvcWrapper::new(parser_pvc).set_spec(parser_pvc).pvc
+}
+
 /// Get Pvc List
 ///
 /// # Arguments
@@ -117,8 +131,7 @@ pub fn get_pvc_list(object: &parser::Object) -> Option<Vec<PersistentVolumeClaim
     let volume_claim = object.volume_claim.as_ref()?;
     let claims = volume_claim
         .iter()
-        .map(|(_, p)| PvcWrapper::new(&p).set_spec(&p))
-        .map(|p| p.pvc)
+        .map(|(_, p)| get_pvc(p))
         .collect::<Vec<PersistentVolumeClaim>>();
 
     Some(claims)
@@ -1 +1,2 @@
-pub mod claim;
\ No newline at end of file
+pub mod claim;
+mod source;
\ No newline at end of file
@@ -0,0 +1,181 @@
+use std::convert::From;
+use k8s_openapi::api::core::v1::{
+    Volume,
+    ConfigMapVolumeSource,
+    SecretVolumeSource,
+    EmptyDirVolumeSource,
+    PersistentVolumeClaimVolumeSource,
+    HostPathVolumeSource,
+    ProjectedVolumeSource,
+    VolumeProjection,
+    ConfigMapProjection,
+    SecretProjection,
+    ServiceAccountTokenProjection,
+    KeyToPath
+};
+use k8s_openapi::apimachinery::pkg::api::resource::Quantity;
+use crate::lib::parser::workload::volume::{
+    Volume as ParserVolume,
+    VolumeSource,
+    Projection,
+    KeyToPath as ParserKeyToPath
+};
+
+impl From<ParserVolume> for Volume {
+    fn from(v: ParserVolume) -> Self {
+        let mut volume = Volume {
+            name: v.name,
+            ..Default::default()
+        };
+
+        match v.source {
+            VolumeSource::ConfigMap(source) => {
+                volume.config_map = Some(ConfigMapVolumeSource {
+                    default_mode: v.default_mode,
+                    items: get_items(source.items),
+                    name: Some(source.name),
+                    optional: source.optional
+                });
+            },
+            VolumeSource::Secret(source) => {
+                volume.secret = Some(SecretVolumeSource {
+                    default_mode: v.default_mode,
+                    items: get_items(source.items),
+                    secret_name: Some(source.name),
+                    optional: source.optional
+                });
+            },
+            VolumeSource::EmptyDir { medium, size_limit } => {
+                volume.empty_dir = Some(EmptyDirVolumeSource {
+                    medium,
+                    size_limit: size_limit.map(Quantity)
+                });
+            },
+            VolumeSource::Claim { name, read_only } => {
+                volume.persistent_volume_claim = Some(PersistentVolumeClaimVolumeSource {
+                    claim_name: name,
+                    read_only
+                });
+            },
+            VolumeSource::HostPath { path, kind } => {
+                volume.host_path = Some(HostPathVolumeSource {
+                    path,
+                    type_: kind
+                });
+            },
+            VolumeSource::Projected(projections) => {
+                volume.projected = Some(ProjectedVolumeSource {
+                    default_mode: v.default_mode,
+                    sources: projections.into_iter().map(VolumeProjection::from).collect()
+                });
+            }
+        }
+
+        volume
+    }
+}
+
+impl From<Projection> for VolumeProjection {
+    fn from(p: Projection) -> Self {
+        match p {
+            Projection::ConfigMap(source) => VolumeProjection {
+                config_map: Some(ConfigMapProjection {
+                    items: get_items(source.items),
+                    name: Some(source.name),
+                    optional: source.optional
+                }),
+                ..Default::default()
+            },
+            Projection::Secret(source) => VolumeProjection {
+                secret: Some(SecretProjection {
+                    items: get_items(source.items),
+                    name: Some(source.name),
+                    optional: source.optional
+                }),
+                ..Default::default()
+            },
+            Projection::ServiceAccountToken { path, audience, expiration_seconds } => VolumeProjection {
+                service_account_token: Some(ServiceAccountTokenProjection {
+                    audience,
+                    expiration_seconds,
+                    path
+                }),
+                ..Default::default()
+            }
+        }
+    }
+}
+
+/// Get Items
+///
+/// # Arguments
+/// * `items` - Vec<ParserKeyToPath>
+///
+/// # Return
+/// Vec<KeyToPath>
+fn get_items(items: Vec<ParserKeyToPath>) -> Vec<KeyToPath> {
+    items
+        .into_iter()
+        .map(|i| KeyToPath {
+            key: i.key,
+            mode: i.mode,
+            path: i.path
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::lib::parser::get_parsed_objects;
+    use super::*;
+
+    #[test]
+    fn expect_to_convert_volumes() {
+        let template = r#"
+        kind = "workload::deployment"
+        name = "nginx"
+        metadata = { name = "nginx" }
+
+        [volumes]
+            [volumes.config]
+                config_map = "nginx-config"
+                default_mode = 0o644
+                items = [
+                    { key = "nginx.conf", path = "nginx.conf" }
+                ]
+
+            [volumes.data]
+                claim = "nginx-data"
+
+            [volumes.token]
+                projected = [
+                    { service_account_token = { path = "token", audience = "vault" } }
+                ]
+
+        [workload]
+            [workload.nginx]
+                image = "nginx"
+                tag = "1.19.10"
+        "#;
+
+        let object = get_parsed_objects(template).unwrap();
+        let workload = object.spec.unwrap().workload.unwrap();
+        let volumes = workload.volumes.unwrap()
+            .into_iter()
+            .map(Volume::from)
+            .collect::<Vec<Volume>>();
+
+        let config = volumes.iter().find(|v| v.name == "config").unwrap();
+        let config_map = config.config_map.as_ref().unwrap();
+        assert_eq!(config_map.name.as_ref().unwrap(), "nginx-config");
+        assert_eq!(config_map.default_mode.unwrap(), 420);
+        assert_eq!(config_map.items.first().unwrap().path, "nginx.conf");
+
+        let data = volumes.iter().find(|v| v.name == "data").unwrap();
+        assert_eq!(data.persistent_volume_claim.as_ref().unwrap().claim_name, "nginx-data");
+
+        let token = volumes.iter().find(|v| v.name == "token").unwrap();
+        let projection = token.projected.as_ref().unwrap().sources.first().unwrap();
+        assert_eq!(projection.service_account_token.as_ref().unwrap().audience.as_ref().unwrap(), "vault");
+    }
+}
\ No newline at end of file
@@ -106,4 +106,18 @@ impl Convert for Vec<String> {
             .map(|s| String::convert(s))
             .collect()
     }
+}
+
+impl Convert for Vec<i64> {
+    fn convert(v: &Value) -> Self {
+        if !v.is_array() {
+            return Vec::new();
+        }
+
+        let array = v.as_array().unwrap();
+        array
+            .iter()
+            .map(i64::convert)
+            .collect()
+    }
 }
\ No newline at end of file
@@ -43,6 +43,38 @@ impl From<regex::Error> for LError {
     }
 }
 
+pub mod common {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        MissingSelectorLabel(String),
+        WrongSelector,
+        PodLabelInSelector(String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::MissingSelectorLabel(key) => write!(f, "selector label `{}` is not defined in the metadata", key),
+                Error::WrongSelector => write!(f, "selector must be a non empty list of keys of the metadata"),
+                Error::PodLabelInSelector(key) => write!(f, "pod label `{}` is a selector label and could not be set in the pod_labels", key)
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
 pub mod network {
     use std::fmt;
     use std::convert::From;
@@ -51,7 +83,21 @@ pub mod network {
     pub enum Error {
         IngressWrongType,
         MissingRules,
-        PathNotFound
+        PathNotFound,
+        PolicyWrongType,
+        WrongDefaultDeny(String),
+        MissingCidr,
+        WrongPolicyPort,
+        UnknownIngressController(String),
+        UnsupportedRewriteTarget,
+        MissingGatewayClass,
+        MissingListeners,
+        MissingListenerPort(String),
+        MissingRouteParent,
+        NamedBackendPort(String),
+        MissingBackendResource(String),
+        WrongTargetPort(String),
+        WrongServiceField(String)
     }
 
     impl fmt::Display for Error {
@@ -59,7 +105,21 @@ pub mod network {
             match self {
                 Error::IngressWrongType => write!(f, "Unable to convert the ingress definition to a map"),
                 Error::MissingRules => write!(f, "Missing ingress [rules] property"),
-                Error::PathNotFound => write!(f, "[paths] not found")
+                Error::PathNotFound => write!(f, "[paths] not found"),
+                Error::PolicyWrongType => write!(f, "Unable to convert the policy rules to a map"),
+                Error::WrongDefaultDeny(value) => write!(f, "Unsupported default_deny value `{}`, expected one of: ingress, egress, all", value),
+                Error::MissingCidr => write!(f, "Missing cidr property of the ip_block"),
+                Error::WrongPolicyPort => write!(f, "The port of a policy rule should be an integer or a named port"),
+                Error::UnknownIngressController(value) => write!(f, "Unsupported ingress preset controller `{}`, expected one of: nginx, traefik", value),
+                Error::UnsupportedRewriteTarget => write!(f, "The rewrite_target preset is not supported by traefik, use a middleware instead"),
+                Error::MissingGatewayClass => write!(f, "Missing gateway class property"),
+                Error::MissingListeners => write!(f, "A gateway should have at least one listener"),
+                Error::MissingListenerPort(name) => write!(f, "Missing port of the listener `{}`", name),
+                Error::MissingRouteParent => write!(f, "A route should have at least one parent with a name"),
+                Error::NamedBackendPort(name) => write!(f, "The port of a route backend should be a number, found `{}`", name),
+                Error::MissingBackendResource(field) => write!(f, "Missing {} of the backend resource", field),
+                Error::WrongTargetPort(value) => write!(f, "The target port `{}` of the service is out of range", value),
+                Error::WrongServiceField(field) => write!(f, "The service field `{}` has a wrong type", field)
             }
         }
     }
@@ -83,6 +143,16 @@ pub mod workload {
     pub enum Error {
         WorkloadNotExist,
         WorkloadMalformatted,
+        UnknownVolumeSource(String),
+        MultipleDefaultContainers,
+        DefaultInitContainer(String),
+        MissingContainerPort,
+        ReservedContainerName(String),
+        MissingGrpcPort,
+        MissingTopologyKey,
+        ContainerSecurityField(String),
+        PodSecurityField(String),
+        MultipleHandlers(String)
     }
 
     impl std::error::Error for Error {}
@@ -91,7 +161,17 @@ pub mod workload {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             match self {
                 Error::WorkloadNotExist => write!(f, "Workload does not exist. Make sure that [workload] is set on the template"),
-                Error::WorkloadMalformatted => write!(f, "Workload is malformatted. Please check that workload is above it's children")
+                Error::WorkloadMalformatted => write!(f, "Workload is malformatted. Please check that workload is above it's children"),
+                Error::UnknownVolumeSource(name) => write!(f, "Unable to find the source of the volume `{}`", name),
+                Error::MultipleDefaultContainers => write!(f, "Only one container could have the default flag"),
+                Error::DefaultInitContainer(name) => write!(f, "The init container `{}` could not have the default flag", name),
+                Error::MissingContainerPort => write!(f, "container_port is required in the ports of a container"),
+                Error::ReservedContainerName(name) => write!(f, "`{}` is a reserved table of the workload and could not be used as a container name", name),
+                Error::MissingGrpcPort => write!(f, "port is required in the grpc action of a probe"),
+                Error::MissingTopologyKey => write!(f, "topology_key is required in a topology spread constraint"),
+                Error::ContainerSecurityField(field) => write!(f, "`{}` is only supported in the security context of a container", field),
+                Error::PodSecurityField(field) => write!(f, "`{}` is only supported in the security context of the pod", field),
+                Error::MultipleHandlers(handlers) => write!(f, "Only one of {} could be set", handlers)
             }
         }
     }
@@ -111,13 +191,197 @@ pub mod crd {
 
     #[derive(Debug)]
     pub enum Error {
-        SpecNotFound
+        FieldsNotFound
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::FieldsNotFound => write!(f, "No table such as [spec] could be founded")
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod scaling {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        AutoscalingNotExist,
+        MissingMaxReplicas,
+        MissingPolicyField(String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::AutoscalingNotExist => write!(f, "Autoscaling does not exist. Make sure that [autoscaling] is set on the template"),
+                Error::MissingMaxReplicas => write!(f, "max_replicas is required in the [autoscaling] table"),
+                Error::MissingPolicyField(field) => write!(f, "{} is required in a scaling policy", field)
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod policy {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        DisruptionNotExist,
+        MissingBudget,
+        BothBudgetSet,
+        WrongBudget(String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::DisruptionNotExist => write!(f, "Disruption does not exist. Make sure that [disruption] is set on the template"),
+                Error::MissingBudget => write!(f, "Either min_available or max_unavailable need to be set in the [disruption] table"),
+                Error::BothBudgetSet => write!(f, "Only one of min_available or max_unavailable could be set in the [disruption] table"),
+                Error::WrongBudget(key) => write!(f, "{} should be an integer or a percentage (i.e: \"50%\")", key)
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod rbac {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        MissingRole,
+        MissingSubjectField(String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::MissingRole => write!(f, "role = {{ name = \"<role name>\" }} is required in the [binding] table"),
+                Error::MissingSubjectField(field) => write!(f, "{} is required in a subject of the [binding] table", field)
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod cluster {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        WrongLimitType(String)
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::WrongLimitType(kind) => write!(f, "Unsupported limit type `{}`, expected one of: container, pod", kind)
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod storage {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        MissingProvisioner
+    }
+
+    impl fmt::Display for Error {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match self {
+                Error::MissingProvisioner => write!(f, "provisioner is required in the [storage_class] table")
+            }
+        }
+    }
+
+    impl std::error::Error for Error {}
+
+    impl From<Error> for super::LError {
+        fn from(err: Error) -> Self {
+            super::LError {
+                message: err.to_string()
+            }
+        }
+    }
+}
+
+pub mod env {
+    use std::fmt;
+    use std::convert::From;
+
+    #[derive(Debug)]
+    pub enum Error {
+        ReadFile(String, String),
+        WrongEnvFileLine(String, usize),
+        DuplicateKey(String)
     }
 
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             match self {
-                Error::SpecNotFound => write!(f, "[Spec] could not be founded")
+                Error::ReadFile(path, err) => write!(f, "Unable to read the file `{}`: {}", path, err),
+                Error::WrongEnvFileLine(path, line) => write!(f, "Expect a KEY=VALUE at the line {} of the env file `{}`", line, path),
+                Error::DuplicateKey(key) => write!(f, "The key `{}` is set in both the data and the binary_data", key)
             }
         }
     }
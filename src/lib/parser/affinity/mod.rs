@@ -5,13 +5,15 @@ use crate::lib::helper::toml::get_value_for_t_lax;
 
 enum AffinityKind {
     Node,
-    Pod
+    Pod,
+    Anti
 }
 
 #[derive(Debug, Default, Clone)]
 pub struct Affinity {
     pub node: Option<AffinityType>,
-    pub pod: Option<AffinityType>
+    pub pod: Option<AffinityType>,
+    pub anti: Option<AffinityType>
 }
 
 #[derive(Debug, Default, Clone)]
@@ -57,7 +59,7 @@ impl Affinity {
     /// Set Affinity
     ///
     /// # Description
-    /// Set the kind of affinity, node, pod
+    /// Set the kind of affinity, node, pod, anti
     ///
     /// # Arguments
     /// * `&mut self` - Self
@@ -92,7 +94,8 @@ impl Affinity {
 
         match kind {
             AffinityKind::Node => self.node = Some(affinity_type),
-            AffinityKind::Pod => self.pod = Some(affinity_type)
+            AffinityKind::Pod => self.pod = Some(affinity_type),
+            AffinityKind::Anti => self.anti = Some(affinity_type)
         }
     }
 }
@@ -174,7 +177,7 @@ impl Expression {
 /// Get Affinity From Ast
 ///
 /// # Description
-/// Create an Affinity which represent nodeAffinity & PodAffinity
+/// Create an Affinity which represent nodeAffinity, PodAffinity & PodAntiAffinity
 ///
 /// # Arguments
 /// * `ast` - &Value
@@ -197,6 +200,10 @@ pub fn get_affinity_from_ast(ast: &Value) -> Option<Affinity> {
         affinity.set_affinity(pod, AffinityKind::Pod);
     }
 
+    if let Some(anti) = affinity_map.get("anti") {
+        affinity.set_affinity(anti, AffinityKind::Anti);
+    }
+
     Some(affinity)
 }
 
@@ -358,4 +365,30 @@ mod tests {
         assert_eq!(region.weight.unwrap(), 1);
         assert_eq!(region.expressions.get(0).unwrap().key, "kubernetes.io/e2e-az-name");
     }
+
+    #[test]
+    fn expect_to_get_pod_anti_affinity() {
+        let template = r#"
+        [affinity]
+            [affinity.anti]
+                [affinity.anti.required]
+                    [affinity.anti.required.hostname]
+                        topology = "kubernetes.io/hostname"
+                        expressions = [
+                            { key = "app", operator = "In", values = ["rusty"] }
+                        ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let affinity_ast = ast.get("affinity").unwrap();
+
+        let affinity = get_affinity_from_ast(affinity_ast).unwrap();
+        assert!(affinity.pod.is_none());
+
+        let anti = affinity.anti.unwrap();
+        let hostname = anti.required.unwrap();
+        let hostname = hostname.get("hostname").unwrap();
+        assert_eq!(hostname.topology.as_ref().unwrap(), "kubernetes.io/hostname");
+        assert_eq!(hostname.expressions.first().unwrap().values, vec!["rusty"]);
+    }
 }
\ No newline at end of file
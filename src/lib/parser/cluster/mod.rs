@@ -0,0 +1,242 @@
+use toml::Value;
+use std::collections::BTreeMap;
+use crate::lib::helper::error::{
+    LError,
+    cluster::Error
+};
+use crate::lib::helper::conv::Convert;
+use super::workload::resource::{Resources, Resource};
+
+#[derive(Debug, Clone, Default)]
+pub struct Cluster {
+    pub quota: Option<Quota>,
+    pub limits: Option<Vec<Limit>>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Quota {
+    pub resources: Resources,
+    pub count: BTreeMap<String, String>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Limit {
+    pub kind: String,
+    pub default: Option<Resource>,
+    pub default_request: Option<Resource>,
+    pub max: Option<Resource>,
+    pub min: Option<Resource>
+}
+
+impl Cluster {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Cluster struct
+    ///
+    /// # Return
+    /// Self
+    fn new() -> Self {
+        Cluster::default()
+    }
+
+    /// Set Quota
+    ///
+    /// # Description
+    /// Set the quota of a namespace. It has the following toml definition
+    ///
+    /// <root>
+    /// [quota]
+    ///     count = { pods = 10 }
+    ///     [quota.requests]
+    ///     [quota.limits]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Self
+    fn set_quota(mut self, ast: &Value) -> Self {
+        if let Some(quota) = ast.get("quota") {
+            let resources = Resources::new()
+                .set_limits(quota)
+                .set_requests(quota);
+
+            self.quota = Some(Quota {
+                resources,
+                count: get_count(quota.get("count"))
+            });
+        }
+
+        self
+    }
+
+    /// Set Limits
+    ///
+    /// # Description
+    /// Set the limits of a LimitRange. Each table represent a type of limit
+    ///
+    /// <root>
+    /// [limit_range]
+    ///     [limit_range.container]
+    ///     [limit_range.pod]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_limits(mut self, ast: &Value) -> Result<Self, LError> {
+        let limit_range = match ast.get("limit_range").and_then(|l| l.as_table()) {
+            Some(l) => l,
+            None => return Ok(self)
+        };
+
+        let mut limits = Vec::new();
+        for (name, item) in limit_range.into_iter() {
+            let kind = match name.to_lowercase().as_str() {
+                "container" => "Container",
+                "pod" => "Pod",
+                _ => return Err(LError::from(Error::WrongLimitType(name.to_owned())))
+            };
+
+            limits.push(Limit {
+                kind: kind.to_owned(),
+                default: item.get("default").map(Resource::convert),
+                default_request: item.get("default_request").map(Resource::convert),
+                max: item.get("max").map(Resource::convert),
+                min: item.get("min").map(Resource::convert)
+            });
+        }
+
+        self.limits = Some(limits);
+
+        Ok(self)
+    }
+}
+
+/// Get Count
+///
+/// # Description
+/// Retrieve the number of objects allowed by the quota (i.e: pods = 10)
+///
+/// # Arguments
+/// * `ast` - Option<&Value>
+///
+/// # Return
+/// BTreeMap<String, String>
+fn get_count(ast: Option<&Value>) -> BTreeMap<String, String> {
+    let table = match ast.and_then(|a| a.as_table()) {
+        Some(t) => t,
+        None => return BTreeMap::new()
+    };
+
+    table
+        .into_iter()
+        .map(|(k, v)| match v {
+            Value::Integer(number) => (k.to_owned(), number.to_string()),
+            _ => (k.to_owned(), String::convert(v))
+        })
+        .collect()
+}
+
+/// Get Cluster
+///
+/// # Description
+/// Retrieve the cluster resources of a template
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Cluster, LError>
+pub fn get_cluster(ast: &Value) -> Result<Cluster, LError> {
+    Cluster::new()
+        .set_quota(ast)
+        .set_limits(ast)
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_quota() {
+        let template = "
+        kind = 'cluster::quota'
+        name = 'rusty-quota'
+        metadata = { name = 'rusty-quota' }
+
+        [quota]
+            count = { pods = 10, services = '5' }
+
+            [quota.requests]
+                cpu = '4'
+                memory = '8Gi'
+
+            [quota.limits]
+                memory = '16Gi'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let cluster = get_cluster(&ast).unwrap();
+        let quota = cluster.quota.unwrap();
+
+        assert_eq!(quota.count.get("pods").unwrap(), "10");
+        assert_eq!(quota.count.get("services").unwrap(), "5");
+
+        let requests = quota.resources.requests.unwrap();
+        assert_eq!(requests.cpu.unwrap(), "4");
+        assert_eq!(requests.memory.unwrap(), "8Gi");
+
+        let limits = quota.resources.limits.unwrap();
+        assert!(limits.cpu.is_none());
+        assert_eq!(limits.memory.unwrap(), "16Gi");
+    }
+
+    #[test]
+    fn expect_to_parse_limit_range() {
+        let template = "
+        kind = 'cluster::limitrange'
+        name = 'rusty-limits'
+        metadata = { name = 'rusty-limits' }
+
+        [limit_range]
+            [limit_range.container]
+                default = { cpu = '500m', memory = '512Mi' }
+                default_request = { cpu = '100m', memory = '128Mi' }
+
+            [limit_range.pod]
+                max = { cpu = '2' }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let limits = get_cluster(&ast).unwrap().limits.unwrap();
+        assert_eq!(limits.len(), 2);
+
+        let container = limits.first().unwrap();
+        assert_eq!(container.kind, "Container");
+        assert_eq!(container.default.as_ref().unwrap().memory.as_ref().unwrap(), "512Mi");
+        assert_eq!(container.default_request.as_ref().unwrap().cpu.as_ref().unwrap(), "100m");
+        assert!(container.max.is_none());
+
+        let pod = limits.last().unwrap();
+        assert_eq!(pod.kind, "Pod");
+        assert_eq!(pod.max.as_ref().unwrap().cpu.as_ref().unwrap(), "2");
+    }
+
+    #[test]
+    fn expect_to_fail_wrong_limit_type() {
+        let template = "
+        [limit_range]
+            [limit_range.node]
+                max = { cpu = '2' }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_cluster(&ast).is_err());
+    }
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use toml::Value;
 use serde::Serialize;
 use crate::lib::helper::error::{
@@ -5,9 +6,19 @@ use crate::lib::helper::error::{
     crd::Error
 };
 
+// Keys of the template which are not part of the custom resource body
+const TEMPLATE_KEYS: [&str; 6] = [
+    "kind",
+    "version",
+    "name",
+    "namespace",
+    "metadata",
+    "annotations"
+];
+
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct CustomCrd {
-    pub spec: Option<Value>
+    pub fields: BTreeMap<String, Value>
 }
 
 impl CustomCrd {
@@ -17,19 +28,20 @@ impl CustomCrd {
     /// Create a new CustomCrd
     ///
     /// # Description
-    /// * `ast` - &Value
+    /// * `fields` - BTreeMap<String, Value>
     ///
     /// # Return
     /// Self
-    fn new(ast: &Value) -> Self {
-        CustomCrd { spec: Some(ast.to_owned()) }
+    fn new(fields: BTreeMap<String, Value>) -> Self {
+        CustomCrd { fields }
     }
 }
 
 /// Get Custom Crd
 ///
 /// # Description
-/// Get the CustomCrd wrapper
+/// Get the CustomCrd wrapper. Every top-level keys which aren't template keys
+/// (i.e: spec, data, rules, webhooks, stringData...) are copied to the custom resource
 ///
 /// # Arguments
 /// * `ast` - &Value
@@ -37,10 +49,19 @@ impl CustomCrd {
 /// # Return
 /// Result<CustomCrd, LError>
 pub fn get_custom_crd(ast: &Value) -> Result<CustomCrd, LError> {
-    let crd = ast.get("spec")
-        .ok_or_else(|| LError::from(Error::SpecNotFound))?;
+    let table = ast.as_table()
+        .ok_or_else(|| LError::from(Error::FieldsNotFound))?;
+
+    let fields = table.iter()
+        .filter(|(key, _)| !TEMPLATE_KEYS.contains(&key.as_str()))
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect::<BTreeMap<String, Value>>();
 
-    Ok(CustomCrd::new(crd))
+    if fields.is_empty() {
+        return Err(LError::from(Error::FieldsNotFound));
+    }
+
+    Ok(CustomCrd::new(fields))
 }
 
 #[cfg(test)]
@@ -68,17 +89,36 @@ mod test {
     }
 
     #[test]
-    fn expect_to_fail_spec_not_found() {
+    fn expect_to_parse_top_level_tables() {
+        let template = r#"
+        kind = "custom::SealedSecret"
+        version = "bitnami.com/v1alpha1"
+        metadata = { name = "rusty-secret" }
+
+        [data]
+            foo = "bar"
+
+        [[rules]]
+            apiGroups = [""]
+            resources = ["secrets"]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let custom = super::get_custom_crd(&ast).unwrap();
+
+        assert_eq!(custom.fields.len(), 2);
+        assert!(custom.fields.contains_key("data"));
+        assert!(custom.fields.get("rules").unwrap().is_array());
+        assert!(!custom.fields.contains_key("metadata"));
+    }
+
+    #[test]
+    fn expect_to_fail_fields_not_found() {
         let template = r#"
         kind = "custom::ManagedCertificate"
         version = "networking.gke.io/v1"
+        namespace = "foo"
         metadata = { name = "rusty-certificate" }
-
-        [foo]
-            domains = [
-                "rusty-dev.co.kr",
-                "rusty-dyn.co.kr"
-            ]
         "#;
 
         let ast = template.parse::<Value>().unwrap();
@@ -0,0 +1,118 @@
+use std::fs;
+use std::path::Path;
+use std::collections::BTreeMap;
+use crate::lib::helper::error::{
+    LError,
+    env::Error
+};
+
+// Constant
+const COMMENT: char = '#';
+const SEPARATOR: char = '=';
+const QUOTES: [char; 2] = ['"', '\''];
+
+/// Content
+///
+/// # Description
+/// Content of a file. A file which isn't a valid UTF-8 file is a binary file
+#[derive(Debug, Clone, PartialEq)]
+pub enum Content {
+    Text(String),
+    Binary(Vec<u8>)
+}
+
+/// Read File
+///
+/// # Arguments
+/// * `path` - &Path
+///
+/// # Return
+/// Result<Content, LError>
+pub fn read_file(path: &Path) -> Result<Content, LError> {
+    let bytes = fs::read(path)
+        .map_err(|err| LError::from(Error::ReadFile(path.display().to_string(), err.to_string())))?;
+
+    match String::from_utf8(bytes) {
+        Ok(text) => Ok(Content::Text(text)),
+        Err(err) => Ok(Content::Binary(err.into_bytes()))
+    }
+}
+
+/// Read Dir
+///
+/// # Description
+/// Read every file of a folder. The name of a file is used as the key. Sub folders are ignored
+///
+/// # Arguments
+/// * `path` - &Path
+///
+/// # Return
+/// Result<BTreeMap<String, Content>, LError>
+pub fn read_dir(path: &Path) -> Result<BTreeMap<String, Content>, LError> {
+    let dir = fs::read_dir(path)
+        .map_err(|err| LError::from(Error::ReadFile(path.display().to_string(), err.to_string())))?;
+
+    let mut contents = BTreeMap::new();
+    for entry in dir {
+        let file = entry?.path();
+        if !file.is_file() {
+            continue;
+        }
+
+        if let Some(name) = file.file_name().and_then(|n| n.to_str()) {
+            contents.insert(name.to_owned(), read_file(&file)?);
+        }
+    }
+
+    Ok(contents)
+}
+
+/// Read Env File
+///
+/// # Description
+/// Read a dotenv file which contains a KEY=VALUE per line. Empty lines & comments are skipped.
+/// The quotes surrounding a value are removed
+///
+/// # Arguments
+/// * `path` - &Path
+///
+/// # Return
+/// Result<BTreeMap<String, String>, LError>
+pub fn read_env_file(path: &Path) -> Result<BTreeMap<String, String>, LError> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| LError::from(Error::ReadFile(path.display().to_string(), err.to_string())))?;
+
+    let mut variables = BTreeMap::new();
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(COMMENT) {
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once(SEPARATOR)
+            .filter(|(key, _)| !key.trim().is_empty())
+            .ok_or_else(|| LError::from(Error::WrongEnvFileLine(path.display().to_string(), idx + 1)))?;
+
+        variables.insert(key.trim().to_owned(), unquote(value.trim()).to_owned());
+    }
+
+    Ok(variables)
+}
+
+/// Unquote
+///
+/// # Arguments
+/// * `value` - &str
+///
+/// # Return
+/// &str
+fn unquote(value: &str) -> &str {
+    for quote in QUOTES.iter() {
+        if value.len() > 1 && value.starts_with(*quote) && value.ends_with(*quote) {
+            return &value[1..value.len() - 1];
+        }
+    }
+
+    value
+}
\ No newline at end of file
@@ -1,11 +1,22 @@
 use toml::Value;
+use std::path::Path;
 use std::collections::BTreeMap;
+use crate::lib::helper::error::{
+    LError,
+    env::Error
+};
 use crate::lib::helper::toml::get_value_for_t_lax;
 
+mod file;
+
 #[derive(Debug, Default, Clone)]
 pub struct Env {
     pub binary: bool,
-    pub data: Option<BTreeMap<String, String>>
+    pub data: Option<BTreeMap<String, String>>,
+    pub binary_data: BTreeMap<String, Vec<u8>>,
+    pub files: Option<BTreeMap<String, String>>,
+    pub from_dir: Option<String>,
+    pub env_file: Option<String>
 }
 
 impl Env {
@@ -23,8 +34,67 @@ impl Env {
         Env {
             binary,
             data,
+            files: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "files"),
+            from_dir: get_value_for_t_lax::<String>(ast, "from_dir"),
+            env_file: get_value_for_t_lax::<String>(ast, "env_file"),
+            ..Default::default()
         }
     }
+
+    /// Load Files
+    ///
+    /// # Description
+    /// Load the env_file, the from_dir folder & the files in the data. The paths are relative to the folder
+    /// of the template. Binary files are set in the binary_data. The values of the [data] table take precedence
+    /// over the values loaded from the files. A key could not be set in both the data & the binary_data
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `dir` - &Path
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    pub fn load_files(mut self, dir: &Path) -> Result<Self, LError> {
+        let mut data = BTreeMap::new();
+        if let Some(env_file) = &self.env_file {
+            data.extend(file::read_env_file(&dir.join(env_file))?);
+        }
+
+        let mut contents = BTreeMap::new();
+        if let Some(from_dir) = &self.from_dir {
+            contents.extend(file::read_dir(&dir.join(from_dir))?);
+        }
+
+        if let Some(files) = &self.files {
+            for (key, path) in files {
+                contents.insert(key.to_owned(), file::read_file(&dir.join(path))?);
+            }
+        }
+
+        for (key, content) in contents {
+            match content {
+                file::Content::Text(text) => { data.insert(key, text); },
+                file::Content::Binary(bytes) => { self.binary_data.insert(key, bytes); }
+            }
+        }
+
+        let duplicate = data.keys()
+            .chain(self.data.iter().flat_map(|d| d.keys()))
+            .find(|key| self.binary_data.contains_key(*key));
+
+        if let Some(key) = duplicate {
+            return Err(LError::from(Error::DuplicateKey(key.to_owned())));
+        }
+
+        if data.is_empty() {
+            return Ok(self);
+        }
+
+        data.extend(self.data.take().unwrap_or_default());
+        self.data = Some(data);
+
+        Ok(self)
+    }
 }
 
 /// Get Env
@@ -99,4 +169,74 @@ mod tests {
         assert_eq!(env.binary, true);
         assert!(env.data.is_none());
     }
+
+    #[test]
+    fn expect_to_load_files() {
+        let template = r#"
+        env_file = "files/nginx.env"
+        files = { "nginx.conf" = "files/nginx.conf" }
+
+        [data]
+            WORKER_PROCESSES = "4"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let env = super::get_env(&ast).unwrap().load_files(&dir).unwrap();
+
+        let data = env.data.unwrap();
+        assert!(data.get("nginx.conf").unwrap().contains("worker_processes"));
+        assert_eq!(data.get("SERVER_NAME").unwrap(), "foo.bar.com");
+        assert_eq!(data.get("WORKER_PROCESSES").unwrap(), "4");
+        assert!(env.binary_data.is_empty());
+    }
+
+    #[test]
+    fn expect_to_load_binary_files_from_dir() {
+        let dir = std::env::temp_dir().join(format!("maomao-env-from-dir-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("favicon.ico"), [0, 159, 146, 150]).unwrap();
+        std::fs::write(dir.join("robots.txt"), "User-agent: *").unwrap();
+
+        let template = format!("from_dir = '{}'", dir.display());
+        let ast = template.parse::<Value>().unwrap();
+        let env = super::get_env(&ast).unwrap().load_files(std::path::Path::new("."));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let env = env.unwrap();
+        assert_eq!(env.data.unwrap().get("robots.txt").unwrap(), "User-agent: *");
+        assert_eq!(env.binary_data.get("favicon.ico").unwrap(), &vec![0, 159, 146, 150]);
+    }
+
+    #[test]
+    fn expect_to_fail_missing_file() {
+        let template = r#"
+        files = { "nginx.conf" = "files/unknown.conf" }
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let env = super::get_env(&ast).unwrap().load_files(std::path::Path::new("examples"));
+        assert!(env.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_key_in_data_and_binary_data() {
+        let dir = std::env::temp_dir().join(format!("maomao-env-duplicate-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("favicon.ico"), [0, 159, 146, 150]).unwrap();
+
+        let template = format!(r#"
+        files = {{ "favicon.ico" = '{}' }}
+
+        [data]
+            "favicon.ico" = "foo"
+        "#, dir.join("favicon.ico").display());
+
+        let ast = template.parse::<Value>().unwrap();
+        let env = super::get_env(&ast).unwrap().load_files(std::path::Path::new("."));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(env.is_err());
+        assert_eq!(env.unwrap_err().message, "The key `favicon.ico` is set in both the data and the binary_data");
+    }
 }
\ No newline at end of file
@@ -3,13 +3,22 @@ pub mod network;
 pub mod workload;
 pub mod volume;
 pub mod env;
+pub mod scaling;
+pub mod policy;
+pub mod rbac;
+pub mod cluster;
+pub mod storage;
 
 mod crd;
 mod spec;
 
+use std::path::Path;
 use std::collections::{BTreeMap, HashMap};
 use toml::Value;
-use super::helper::error::LError;
+use super::helper::error::{
+    LError,
+    common::Error
+};
 use super::helper::toml::{
     get_value_for_t,
     get_value_for_t_lax
@@ -18,6 +27,7 @@ use super::helper::conv::Convert;
 
 // Constant
 const SPLIT_DELIMITER: &str = "::";
+const DEFAULT_SELECTOR_LABEL: &str = "name";
 
 /// Kind
 ///
@@ -25,12 +35,22 @@ const SPLIT_DELIMITER: &str = "::";
 /// Kind of toml file
 /// - Workload => workload::{kubernetes workfload} i.e workload::deployment
 /// - Network => network::{kubernetes network object} i.e: network::service
+/// - Scaling => scaling::{kubernetes scaling object} i.e: scaling::hpa
+/// - Policy => policy::{kubernetes policy object} i.e: policy::pdb
+/// - Rbac => rbac::{kubernetes rbac object} i.e: rbac::role
+/// - Cluster => cluster::{kubernetes namespace object} i.e: cluster::namespace
+/// - Storage => storage::{kubernetes storage object} i.e: storage::pvc
 #[derive(Debug, PartialEq, Clone)]
 pub enum Kind {
     Workload(String),
     Network(String),
     Env(String),
     Custom(String),
+    Scaling(String),
+    Policy(String),
+    Rbac(String),
+    Cluster(String),
+    Storage(String),
     None
 }
 
@@ -40,6 +60,27 @@ impl Default for Kind {
     }
 }
 
+impl Kind {
+    /// Is Namespaced
+    ///
+    /// # Description
+    /// Check whenever the kind is a namespaced object. Namespaces, cluster roles, cluster role bindings
+    /// & storage classes are cluster scoped objects. The scope of a custom kind is unknown thus it's not
+    /// considered as a namespaced object
+    ///
+    /// # Return
+    /// bool
+    pub fn is_namespaced(&self) -> bool {
+        match self {
+            Kind::Cluster(kind) => kind != "namespace",
+            Kind::Rbac(kind) => kind != "clusterrole" && kind != "clusterrolebinding",
+            Kind::Storage(kind) => kind != "class",
+            Kind::Custom(_) => false,
+            _ => true
+        }
+    }
+}
+
 impl Convert for Kind {
     fn convert(v: &Value) -> Self {
         let kind = get_value_for_t::<String>(v, "kind");
@@ -67,6 +108,11 @@ impl Convert for Kind {
             "network" => Kind::Network(arg),
             "env" => Kind::Env(arg),
             "custom" => Kind::Custom(arg),
+            "scaling" => Kind::Scaling(arg),
+            "policy" => Kind::Policy(arg),
+            "rbac" => Kind::Rbac(arg),
+            "cluster" => Kind::Cluster(arg),
+            "storage" => Kind::Storage(arg),
             _ => Kind::None
         }
     }
@@ -80,6 +126,7 @@ pub struct Object {
     pub namespace: Option<String>,
     
     pub metadata: BTreeMap<String, String>,
+    pub selector: BTreeMap<String, String>,
     pub annotations: Option<BTreeMap<String, String>>,
     pub spec: Option<spec::Spec>,
 
@@ -94,6 +141,7 @@ impl Object {
     /// Construct a new Object structure by filling the basic informations
     /// - name
     /// - metadata
+    /// - selector
     /// - kind (kind of template)
     ///
     /// # Arguments
@@ -107,12 +155,18 @@ impl Object {
         let metadata = get_value_for_t::<BTreeMap<String, String>>(ast, "metadata")?;
         let namespace = get_value_for_t_lax::<String>(ast, "namespace");
         let kind = Kind::convert(ast);
+        // the selector of a custom template is a field of the custom resource
+        let selector = match kind {
+            Kind::Custom(_) => BTreeMap::new(),
+            _ => get_selector(ast, &metadata)?
+        };
         
         Ok(Object {
             kind,
             name,
             version,
             metadata,
+            selector,
             namespace,
             annotations: None,
             spec: None,
@@ -140,13 +194,23 @@ impl Object {
     /// Set Spec
     ///
     /// # Description
-    /// Set the spec field in the Object struct
+    /// Set the spec field in the Object struct. The pod_labels of a workload could not override
+    /// a selector label as the pods would not match the selector of the workload anymore
     ///
     /// # Arguments
     /// * `mut self` - Self
     /// * `ast` - &Value
     fn set_spec(mut self, ast: &Value) -> Self {
-        let spec = spec::get_spec(ast, &self.kind);
+        let mut spec = spec::get_spec(ast, &self.kind);
+        let selector_label = spec.workload.as_ref()
+            .and_then(|w| w.pod_labels.as_ref())
+            .and_then(|labels| labels.keys().find(|key| self.selector.contains_key(*key)));
+
+        if let Some(key) = selector_label {
+            spec.error = Some(LError::from(Error::PodLabelInSelector(key.to_owned())));
+            spec.workload = None;
+        }
+
         self.spec = Some(spec);
 
         self
@@ -199,6 +263,63 @@ impl Object {
 
         self
     }
+
+    /// Load Files
+    ///
+    /// # Description
+    /// Load the files referenced by the template such as the files of a configmap or a secret
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `dir` - &Path folder of the template
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    pub fn load_files(mut self, dir: &Path) -> Result<Self, LError> {
+        if let Some(spec) = self.spec.as_mut() {
+            if let Some(env) = spec.env.take() {
+                spec.env = Some(env.load_files(dir)?);
+            }
+        }
+
+        Ok(self)
+    }
+}
+
+/// Get Selector
+///
+/// # Description
+/// Retrieve the labels used to select the pods. The selector is a list of keys of the metadata i.e: selector = ["name", "tier"]
+/// When the selector is not set, the `name` label of the metadata is used. Without a `name` label every labels of the metadata are used
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `metadata` - &BTreeMap<String, String>
+///
+/// # Return
+/// Result<BTreeMap<String, String>, LError>
+fn get_selector(ast: &Value, metadata: &BTreeMap<String, String>) -> Result<BTreeMap<String, String>, LError> {
+    let keys = match ast.get("selector") {
+        Some(Value::Array(keys)) if !keys.is_empty() => keys.iter()
+            .map(|key| key.as_str().map(|k| k.to_owned()).ok_or_else(|| LError::from(Error::WrongSelector)))
+            .collect::<Result<Vec<String>, LError>>()?,
+        Some(_) => return Err(LError::from(Error::WrongSelector)),
+        None => {
+            let selector = match metadata.get_key_value(DEFAULT_SELECTOR_LABEL) {
+                Some((k, v)) => std::iter::once((k.to_owned(), v.to_owned())).collect(),
+                None => metadata.to_owned()
+            };
+
+            return Ok(selector);
+        }
+    };
+
+    keys.into_iter()
+        .map(|key| match metadata.get(&key) {
+            Some(value) => Ok((key, value.to_owned())),
+            None => Err(LError::from(Error::MissingSelectorLabel(key)))
+        })
+        .collect()
 }
 
 /// Get Parsed Objects
@@ -252,6 +373,113 @@ mod test {
         assert_eq!(object.namespace.unwrap(), "bar");
     }
 
+    #[test]
+    fn expect_to_parse_selector() {
+        let default = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty', tier = 'backend' }
+        ";
+
+        let object = super::get_parsed_objects(default).unwrap();
+        assert_eq!(object.selector.len(), 1);
+        assert_eq!(object.selector.get("name").unwrap(), "rusty");
+
+        let keys = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty', tier = 'backend', version = 'v2' }
+            selector = ['name', 'tier']
+        ";
+
+        let object = super::get_parsed_objects(keys).unwrap();
+        assert_eq!(object.selector.len(), 2);
+        assert_eq!(object.selector.get("tier").unwrap(), "backend");
+
+        let unnamed = "
+            kind = 'workload::deployment'
+            metadata = { app = 'rusty', tier = 'backend' }
+        ";
+
+        let object = super::get_parsed_objects(unnamed).unwrap();
+        assert_eq!(object.selector.len(), 2);
+        assert_eq!(object.selector.get("app").unwrap(), "rusty");
+    }
+
+    #[test]
+    fn expect_to_fail_table_selector() {
+        let template = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty' }
+            selector = { app = 'rust' }
+        ";
+
+        let object = super::get_parsed_objects(template);
+        assert!(object.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_non_string_selector() {
+        let template = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty' }
+            selector = ['name', 1]
+        ";
+
+        let object = super::get_parsed_objects(template);
+        assert!(object.is_err());
+        assert_eq!(object.unwrap_err().message, "selector must be a non empty list of keys of the metadata");
+    }
+
+    #[test]
+    fn expect_to_not_parse_selector_of_custom_kind() {
+        let template = "
+            kind = 'custom::PodMonitor'
+            version = 'monitoring.coreos.com/v1'
+            metadata = { name = 'rusty' }
+
+            [selector]
+                matchLabels = { app = 'rusty' }
+        ";
+
+        let object = super::get_parsed_objects(template).unwrap();
+        assert!(object.selector.is_empty());
+
+        let crd = object.spec.unwrap().crd.unwrap();
+        assert!(crd.fields.contains_key("selector"));
+    }
+
+    #[test]
+    fn expect_to_fail_missing_selector_label() {
+        let template = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty' }
+            selector = ['tier']
+        ";
+
+        let object = super::get_parsed_objects(template);
+        assert!(object.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_pod_label_in_selector() {
+        let template = "
+            kind = 'workload::deployment'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                pod_labels = { name = 'rusty-canary' }
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        ";
+
+        let object = super::get_parsed_objects(template).unwrap();
+        let spec = object.spec.unwrap();
+
+        assert!(spec.workload.is_none());
+        assert_eq!(spec.error.unwrap().message, "pod label `name` is a selector label and could not be set in the pod_labels");
+    }
+
     #[test]
     fn expect_to_parse_annotations() {
         let template = "
@@ -1,28 +1,91 @@
 use toml::Value;
-use crate::lib::helper::conv::Convert;
-use crate::lib::helper::toml::get_value_for_t;
+use crate::lib::helper::error::{
+    LError,
+    network::Error
+};
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
+use super::policy::Port;
 
-#[derive(Debug, Default, Clone)]
+// Constant
+const DEFAULT_PORT: i32 = 80;
+
+/// Backend
+///
+/// # Description
+/// Backend of an ingress. The port of the service could be a number or the name of a port of the service.
+/// When a resource is set, the backend target the resource instead of the service
+#[derive(Debug, Clone)]
 pub struct Backend {
     pub name: String,
-    pub port: i64
+    pub port: Port,
+    pub resource: Option<Resource>
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct Resource {
-    api_group: String,
-    kind: String,
-    name: String
+    pub api_group: Option<String>,
+    pub kind: String,
+    pub name: String
+}
+
+impl Default for Backend {
+    fn default() -> Self {
+        Backend {
+            name: String::new(),
+            port: Port::Number(DEFAULT_PORT),
+            resource: None
+        }
+    }
 }
 
-impl Convert for Backend {
-    fn convert(data: &Value) -> Self {
+impl Resource {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Resource. The kind and the name of the resource are required
+    ///
+    /// # Arguments
+    /// * `data` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(data: &Value) -> Result<Self, LError> {
+        let kind = get_value_for_t::<String>(data, "kind")
+            .map_err(|_| LError::from(Error::MissingBackendResource("kind".to_owned())))?;
+        let name = get_value_for_t::<String>(data, "name")
+            .map_err(|_| LError::from(Error::MissingBackendResource("name".to_owned())))?;
+
+        Ok(Resource {
+            api_group: get_value_for_t_lax::<String>(data, "api_group"),
+            kind,
+            name
+        })
+    }
+}
+
+impl Backend {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Backend
+    ///
+    /// # Arguments
+    /// * `data` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    pub fn new(data: &Value) -> Result<Self, LError> {
         let name = get_value_for_t::<String>(&data, "name").unwrap_or_default();
-        let port = get_value_for_t::<i64>(&data, "port").unwrap_or(80);
+        let port = match data.get("port") {
+            Some(Value::String(name)) => Port::Name(name.to_owned()),
+            Some(Value::Integer(number)) => Port::Number(*number as i32),
+            _ => Port::Number(DEFAULT_PORT)
+        };
 
-        Backend {
+        Ok(Backend {
             name,
-            port
-        }
+            port,
+            resource: data.get("resource").map(Resource::new).transpose()?
+        })
     }
 }
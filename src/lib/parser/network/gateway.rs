@@ -0,0 +1,184 @@
+use toml::Value;
+use crate::lib::helper::error::{
+    LError,
+    network::Error
+};
+use crate::lib::helper::toml::{
+    get_value_for_t,
+    get_value_for_t_lax
+};
+
+// Constant
+const DEFAULT_PROTOCOL: &str = "HTTP";
+
+/// Gateway
+///
+/// # Description
+/// Gateway of the Gateway API. The listeners are defined as named tables
+#[derive(Debug, Clone, Default)]
+pub struct Gateway {
+    pub class: String,
+    pub listeners: Vec<Listener>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Listener {
+    pub name: String,
+    pub port: i32,
+    pub protocol: String,
+    pub hostname: Option<String>,
+    pub tls: Option<String>,
+    pub allowed_routes: Option<String>
+}
+
+impl Listener {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Listener. The port default to 80 for HTTP & 443 for HTTPS
+    ///
+    /// # Arguments
+    /// * `name` - &str
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(name: &str, ast: &Value) -> Result<Self, LError> {
+        let protocol = get_value_for_t_lax::<String>(ast, "protocol")
+            .unwrap_or_else(|| DEFAULT_PROTOCOL.to_owned());
+
+        let port = match (get_value_for_t_lax::<i32>(ast, "port"), protocol.as_str()) {
+            (Some(port), _) => port,
+            (None, "HTTP") => 80,
+            (None, "HTTPS") => 443,
+            (None, _) => return Err(LError::from(Error::MissingListenerPort(name.to_owned())))
+        };
+
+        Ok(Listener {
+            name: name.to_owned(),
+            port,
+            protocol,
+            hostname: get_value_for_t_lax::<String>(ast, "hostname"),
+            tls: get_value_for_t_lax::<String>(ast, "tls"),
+            allowed_routes: get_value_for_t_lax::<String>(ast, "allowed_routes")
+        })
+    }
+}
+
+/// Get Gateway
+///
+/// # Description
+/// Retrieve the gateway. It has the following toml definition
+///
+/// [gateway]
+///     class = "<gateway class>"
+///
+///     [gateway.listeners.<name>]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Gateway, LError>
+pub fn get_gateway(ast: &Value) -> Result<Gateway, LError> {
+    let class = get_value_for_t::<String>(ast, "class")
+        .map_err(|_| LError::from(Error::MissingGatewayClass))?;
+
+    let listeners = match ast.get("listeners").and_then(|l| l.as_table()) {
+        Some(table) => table
+            .iter()
+            .map(|(name, item)| Listener::new(name, item))
+            .collect::<Result<Vec<Listener>, LError>>()?,
+        None => Vec::new()
+    };
+
+    if listeners.is_empty() {
+        return Err(LError::from(Error::MissingListeners));
+    }
+
+    Ok(Gateway {
+        class,
+        listeners
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_gateway() {
+        let template = "
+        [gateway]
+            class = 'istio'
+
+            [gateway.listeners]
+                [gateway.listeners.http]
+                    hostname = '*.foo.bar.com'
+
+                [gateway.listeners.https]
+                    protocol = 'HTTPS'
+                    tls = 'foo-ssl-certificates'
+                    allowed_routes = 'All'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let gateway = get_gateway(ast.get("gateway").unwrap()).unwrap();
+        assert_eq!(gateway.class, "istio");
+        assert_eq!(gateway.listeners.len(), 2);
+
+        let http = gateway.listeners.first().unwrap();
+        assert_eq!(http.name, "http");
+        assert_eq!(http.port, 80);
+        assert_eq!(http.protocol, "HTTP");
+        assert_eq!(http.hostname.as_ref().unwrap(), "*.foo.bar.com");
+
+        let https = gateway.listeners.last().unwrap();
+        assert_eq!(https.port, 443);
+        assert_eq!(https.tls.as_ref().unwrap(), "foo-ssl-certificates");
+        assert_eq!(https.allowed_routes.as_ref().unwrap(), "All");
+    }
+
+    #[test]
+    fn expect_to_fail_missing_class() {
+        let template = "
+        [gateway]
+            [gateway.listeners]
+                [gateway.listeners.http]
+                    port = 8080
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_gateway(ast.get("gateway").unwrap()).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_listener_port() {
+        let template = "
+        [gateway]
+            class = 'istio'
+
+            [gateway.listeners]
+                [gateway.listeners.tcp]
+                    protocol = 'TCP'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_gateway(ast.get("gateway").unwrap()).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_without_listeners() {
+        let template = "
+        [gateway]
+            class = 'istio'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let gateway = get_gateway(ast.get("gateway").unwrap());
+
+        assert!(gateway.is_err());
+        assert_eq!(gateway.unwrap_err().message, "A gateway should have at least one listener");
+    }
+}
\ No newline at end of file
@@ -11,17 +11,23 @@ use crate::lib::helper::toml::{
 use crate::lib::helper::conv::Convert;
 use super::backend;
 
+// Constant
+const DEFAULT_PATH_TYPE: &str = "Prefix";
+
 #[derive(Debug, Clone)]
 pub struct Ingress {
+    pub class: Option<String>,
     pub default: Option<backend::Backend>,
     pub rules: Option<Vec<IngressRule>>,
-    pub tls: Option<Tls>
+    pub tls: Option<Vec<Tls>>,
+    pub preset: Option<Preset>
 }
 
 #[derive(Debug, Clone)]
 pub struct IngressRule {
     pub host: String,
-    pub paths: Option<Vec<IngressHTTPPath>>
+    pub paths: Option<Vec<IngressHTTPPath>>,
+    pub tls: Option<String>
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +43,52 @@ pub struct Tls {
     pub secrets: Option<String>
 }
 
+/// Controller
+///
+/// # Description
+/// Ingress controllers supported by the annotation presets
+#[derive(Debug, Clone, PartialEq)]
+pub enum Controller {
+    Nginx,
+    Traefik
+}
+
+/// Preset
+///
+/// # Description
+/// Preset used to generate the annotations of an ingress controller
+#[derive(Debug, Clone)]
+pub struct Preset {
+    pub controller: Controller,
+    pub rewrite_target: Option<String>,
+    pub ssl_redirect: Option<bool>
+}
+
+impl Controller {
+    /// As Str
+    ///
+    /// # Description
+    /// Return the name of the controller. The name is used as the default ingress class
+    ///
+    /// # Return
+    /// &str
+    pub fn as_str(&self) -> &str {
+        match self {
+            Controller::Nginx => "nginx",
+            Controller::Traefik => "traefik"
+        }
+    }
+}
+
+impl Convert for Tls {
+    fn convert(ast: &Value) -> Self {
+        Tls {
+            hosts: get_value_for_t_lax::<Vec<String>>(ast, "hosts"),
+            secrets: get_value_for_t_lax::<String>(ast, "secrets")
+        }
+    }
+}
+
 impl Ingress {
     /// New
     ///
@@ -44,9 +96,11 @@ impl Ingress {
     /// Create a new Ingress struct
     fn new() -> Self {
         Ingress {
+            class: None,
             rules: None,
             default: None,
-            tls: None
+            tls: None,
+            preset: None
         }
     }
 
@@ -76,19 +130,27 @@ impl Ingress {
         for (_, rules) in rules.into_iter() {
             let host = get_value_for_t::<String>(&rules, "host")
                 .unwrap_or_default();
+            let tls = get_value_for_t_lax::<String>(rules, "tls");
 
             let paths = rules.get("paths")
                 .ok_or_else(|| LError::from(Error::PathNotFound))?;
 
-            if paths.is_table() {
+            if let Some(paths) = paths.as_table() {
+                let paths = paths
+                    .values()
+                    .map(IngressHTTPPath::new)
+                    .collect::<Result<Vec<IngressHTTPPath>, LError>>()?;
+
                 ingress_rules.push(IngressRule {
                     host,
-                    paths: Some(Vec::convert(paths))
+                    paths: Some(paths),
+                    tls
                 })
             } else {
                 ingress_rules.push(IngressRule {
                     host,
-                    paths: None
+                    paths: None,
+                    tls
                 })
             }
         }
@@ -115,9 +177,8 @@ impl Ingress {
             return Ok(self)
         }
 
-        let default = get_value_for_t::<backend::Backend>(default.unwrap(), "backend");
-        if let Ok(d) = default {
-            self.default = Some(d)
+        if let Some(backend) = default.unwrap().get("backend") {
+            self.default = Some(backend::Backend::new(backend)?)
         }
 
         Ok(self)
@@ -126,7 +187,8 @@ impl Ingress {
     /// Set Tls
     ///
     /// # Description
-    /// Set TLS struct to ingress struct
+    /// Set the TLS blocks of the ingress. The [tls] table could either be a single block
+    /// or contains several named blocks such as [tls.<name>]
     ///
     /// # Arguments
     /// * `mut self` - Self
@@ -134,33 +196,55 @@ impl Ingress {
     ///
     /// # Return
     /// Result<Self, LError>
-    fn set_tls(mut self, ast: &Value) -> Result<Self, LError> {        
-        let tls_ast = ast.get("tls");
-        if tls_ast.is_none() {
-            return Ok(self);
-        }
+    fn set_tls(mut self, ast: &Value) -> Result<Self, LError> {
+        let tls_ast = match ast.get("tls") {
+            Some(t) => t,
+            None => return Ok(self)
+        };
 
-        let secrets = get_value_for_t_lax::<String>(tls_ast.unwrap(), "secrets");
-        let hosts = get_value_for_t_lax::<Vec<String>>(tls_ast.unwrap(), "hosts");
+        let table = tls_ast
+            .as_table()
+            .ok_or_else(|| LError::from(Error::IngressWrongType))?;
+
+        let tls = if table.contains_key("hosts") || table.contains_key("secrets") {
+            vec![Tls::convert(tls_ast)]
+        } else {
+            table.values().map(Tls::convert).collect::<Vec<Tls>>()
+        };
 
-        let tls = Tls { hosts, secrets };
         self.tls = Some(tls);
         Ok(self)
-    }   
-}
+    }
+
+    /// Set Class
+    ///
+    /// # Description
+    /// Set the ingress class and the annotation preset. The name of the controller of the preset
+    /// is used as the class when the class isn't specified
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_class(mut self, ast: &Value) -> Result<Self, LError> {
+        self.class = get_value_for_t_lax::<String>(ast, "class");
+
+        if let Some(preset_ast) = ast.get("preset") {
+            let preset = get_preset(preset_ast)?;
+            if self.class.is_none() {
+                self.class = Some(preset.controller.as_str().to_owned());
+            }
+
+            self.preset = Some(preset);
+        }
 
-impl Convert for Vec<IngressHTTPPath> {
-    fn convert(v: &Value) -> Self {
-        let paths = v.as_table().unwrap();
-        
-        paths
-            .into_iter()
-            .map(|(_, data)| IngressHTTPPath::from(data.to_owned()))
-            .collect::<Vec<IngressHTTPPath>>()
+        Ok(self)
     }
 }
 
-impl From<Value> for IngressHTTPPath {
+impl IngressHTTPPath {
     /// New
     ///
     /// # Description
@@ -170,25 +254,54 @@ impl From<Value> for IngressHTTPPath {
     /// * `ast` - &Value
     ///
     /// # Return
-    /// Self
-    fn from(ast: Value) -> Self {
-        let kind = get_value_for_t::<String>(&ast, "type").unwrap_or_default();
-        let path = get_value_for_t::<String>(&ast, "path").unwrap_or_default();
-        
-        if let Some(backend_ast) = ast.get("backend") {
-            return IngressHTTPPath {
-                kind,
-                path,
-                backend: backend::Backend::convert(backend_ast)
-            }
-        }
-
-        IngressHTTPPath {
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let kind = get_value_for_t_lax::<String>(ast, "type")
+            .unwrap_or_else(|| DEFAULT_PATH_TYPE.to_owned());
+        let path = get_value_for_t::<String>(ast, "path").unwrap_or_default();
+        let backend = match ast.get("backend") {
+            Some(backend_ast) => backend::Backend::new(backend_ast)?,
+            None => backend::Backend::default()
+        };
+
+        Ok(IngressHTTPPath {
             kind,
             path,
-            backend: backend::Backend::default()
-        }
+            backend
+        })
+    }
+}
+
+/// Get Preset
+///
+/// # Description
+/// Retrieve the annotation preset of an ingress controller. It has the following toml definition
+///
+/// preset = { controller = "nginx", rewrite_target = "/", ssl_redirect = true }
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Preset, LError>
+fn get_preset(ast: &Value) -> Result<Preset, LError> {
+    let name = get_value_for_t::<String>(ast, "controller")?;
+    let controller = match name.as_str() {
+        "nginx" => Controller::Nginx,
+        "traefik" => Controller::Traefik,
+        _ => return Err(LError::from(Error::UnknownIngressController(name)))
+    };
+
+    let rewrite_target = get_value_for_t_lax::<String>(ast, "rewrite_target");
+    if controller == Controller::Traefik && rewrite_target.is_some() {
+        return Err(LError::from(Error::UnsupportedRewriteTarget));
     }
+
+    Ok(Preset {
+        controller,
+        rewrite_target,
+        ssl_redirect: get_value_for_t_lax::<bool>(ast, "ssl_redirect")
+    })
 }
 
 /// Get Ingress
@@ -205,7 +318,8 @@ pub fn get_ingress(ast: &Value) -> Result<Ingress, LError> {
     let ingress = Ingress::new()
         .set_rules(ast)?
         .set_default(ast)?
-        .set_tls(ast)?;
+        .set_tls(ast)?
+        .set_class(ast)?;
 
     Ok(ingress)
 }
@@ -213,6 +327,8 @@ pub fn get_ingress(ast: &Value) -> Result<Ingress, LError> {
 #[cfg(test)]
 mod test {
     use toml::Value;
+    use super::Controller;
+    use crate::lib::parser::network::policy::Port;
 
     #[test]
     fn expect_to_parse_ingress() {
@@ -257,7 +373,7 @@ mod test {
         assert_eq!(first_path.kind, "Prefix");
         assert_eq!(first_path.path, "/");
         assert_eq!(first_path.backend.name, "rusty");
-        assert_eq!(first_path.backend.port, 90);
+        assert_eq!(first_path.backend.port, Port::Number(90));
     }
 
     #[test]
@@ -299,7 +415,7 @@ mod test {
         assert_eq!(path.kind, "Prefix");
         assert_eq!(path.path, "/");
         assert_eq!(path.backend.name, "rusty");
-        assert_eq!(path.backend.port, 90);
+        assert_eq!(path.backend.port, Port::Number(90));
     }
 
     #[test]
@@ -333,7 +449,7 @@ mod test {
         let default = ingress.default.unwrap();
 
         assert_eq!(default.name, "capoo");
-        assert_eq!(default.port, 8000);
+        assert_eq!(default.port, Port::Number(8000));
     }
 
     #[test]
@@ -358,6 +474,7 @@ mod test {
 
         let ingress = ingress.unwrap();
         let tls = ingress.tls.unwrap();
+        let tls = tls.first().unwrap().to_owned();
 
         assert_eq!(tls.hosts.unwrap().get(0).unwrap(), "foo.bar.com");
         assert_eq!(tls.secrets.unwrap(), "foo-ssl-certificates");
@@ -380,6 +497,107 @@ mod test {
         let ingress = ingress.unwrap();
         let tls = ingress.tls.unwrap();
 
-        assert_eq!(tls.secrets.unwrap(), "bar-ssl-certificates");
+        assert_eq!(tls.len(), 1);
+        assert_eq!(tls.first().unwrap().secrets.as_ref().unwrap(), "bar-ssl-certificates");
+    }
+
+    #[test]
+    fn expect_to_parse_multiple_tls() {
+        let template = "
+            [ingress]
+                [ingress.rules]
+                    [ingress.rules.rusty]
+                        host = 'foo.bar.com'
+                        tls = 'foo-ssl-certificates'
+
+                        [ingress.rules.rusty.paths]
+                            [ingress.rules.rusty.paths.0]
+                                path = '/'
+                                backend = { name = 'rusty', port = 'http' }
+
+                [ingress.tls]
+                    [ingress.tls.capoo]
+                        hosts = ['capoo.com']
+                        secrets = 'capoo-ssl-certificates'
+
+                    [ingress.tls.dogdog]
+                        hosts = ['dogdog.com']
+                        secrets = 'dogdog-ssl-certificates'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let ingress_ast = ast.get("ingress").unwrap();
+        let ingress = super::get_ingress(ingress_ast).unwrap();
+
+        let tls = ingress.tls.unwrap();
+        assert_eq!(tls.len(), 2);
+        assert_eq!(tls.last().unwrap().secrets.as_ref().unwrap(), "dogdog-ssl-certificates");
+
+        let rules = ingress.rules.unwrap();
+        let rule = rules.first().unwrap();
+        assert_eq!(rule.tls.as_ref().unwrap(), "foo-ssl-certificates");
+
+        let path = rule.paths.as_ref().unwrap().first().unwrap();
+        assert_eq!(path.kind, "Prefix");
+        assert_eq!(path.backend.port, Port::Name("http".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_parse_class_and_preset() {
+        let template = "
+            [ingress]
+                preset = { controller = 'nginx', rewrite_target = '/', ssl_redirect = true }
+
+                [ingress.default]
+                    backend = { resource = { api_group = 'k8s.example.com', kind = 'StorageBucket', name = 'static-assets' } }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let ingress_ast = ast.get("ingress").unwrap();
+        let ingress = super::get_ingress(ingress_ast).unwrap();
+
+        assert_eq!(ingress.class.unwrap(), "nginx");
+
+        let preset = ingress.preset.unwrap();
+        assert_eq!(preset.controller, Controller::Nginx);
+        assert_eq!(preset.rewrite_target.unwrap(), "/");
+        assert!(preset.ssl_redirect.unwrap());
+
+        let resource = ingress.default.unwrap().resource.unwrap();
+        assert_eq!(resource.api_group.unwrap(), "k8s.example.com");
+        assert_eq!(resource.kind, "StorageBucket");
+        assert_eq!(resource.name, "static-assets");
+    }
+
+    #[test]
+    fn expect_to_fail_traefik_rewrite_target() {
+        let template = "
+            [ingress]
+                class = 'traefik-internal'
+                preset = { controller = 'traefik', rewrite_target = '/' }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let ingress_ast = ast.get("ingress").unwrap();
+        assert!(super::get_ingress(ingress_ast).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_resource_backend_without_name() {
+        let template = "
+            [ingress]
+                [ingress.rules.0]
+                    host = 'foo.bar.com'
+                    [ingress.rules.0.paths.0]
+                        path = '/static'
+                        backend = { resource = { kind = 'StorageBucket' } }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let ingress_ast = ast.get("ingress").unwrap();
+        let ingress = super::get_ingress(ingress_ast);
+
+        assert!(ingress.is_err());
+        assert_eq!(ingress.unwrap_err().message, "Missing name of the backend resource");
     }
 }
\ No newline at end of file
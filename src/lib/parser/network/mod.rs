@@ -1,6 +1,9 @@
 pub mod service;
 pub mod ingress;
 pub mod backend;
+pub mod policy;
+pub mod gateway;
+pub mod route;
 
 use toml::Value;
 use crate::lib::helper::error::LError;
@@ -14,7 +17,10 @@ use crate::lib::helper::error::LError;
 #[derive(Debug, Default, Clone)]
 pub struct Network {
     pub service: Option<service::Service>,
-    pub ingress: Option<ingress::Ingress>
+    pub ingress: Option<ingress::Ingress>,
+    pub policy: Option<policy::NetworkPolicy>,
+    pub gateway: Option<gateway::Gateway>,
+    pub route: Option<route::HttpRoute>
 }
 
 impl Network {
@@ -80,6 +86,72 @@ impl Network {
 
         Ok(self)
     }
+
+    /// Set Policy
+    ///
+    /// # Description
+    /// Set the network policy struct field. It has the following toml definition
+    ///
+    ///  <root>
+    /// [policy]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - Option<&Value>
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_policy(mut self, ast: Option<&Value>) -> Result<Self, LError> {
+        if let Some(node) = ast {
+            self.policy = Some(policy::get_network_policy(node)?);
+        }
+
+        Ok(self)
+    }
+
+    /// Set Gateway
+    ///
+    /// # Description
+    /// Set the gateway struct field. It has the following toml definition
+    ///
+    ///  <root>
+    /// [gateway]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - Option<&Value>
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_gateway(mut self, ast: Option<&Value>) -> Result<Self, LError> {
+        if let Some(node) = ast {
+            self.gateway = Some(gateway::get_gateway(node)?);
+        }
+
+        Ok(self)
+    }
+
+    /// Set Route
+    ///
+    /// # Description
+    /// Set the http route struct field. It has the following toml definition
+    ///
+    ///  <root>
+    /// [route]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - Option<&Value>
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_route(mut self, ast: Option<&Value>) -> Result<Self, LError> {
+        if let Some(node) = ast {
+            self.route = Some(route::get_http_route(node)?);
+        }
+
+        Ok(self)
+    }
 }
 
 /// Get Network
@@ -99,10 +171,16 @@ pub fn get_network(ast: &Value) -> Result<Network, LError> {
     // Retrieve the service object from toml_field
     let service_field = ast.get("service");
     let ingress_field = ast.get("ingress");
+    let policy_field = ast.get("policy");
+    let gateway_field = ast.get("gateway");
+    let route_field = ast.get("route");
 
     let network = network
         .set_service(service_field)?
-        .set_ingress(ingress_field)?;
+        .set_ingress(ingress_field)?
+        .set_policy(policy_field)?
+        .set_gateway(gateway_field)?
+        .set_route(route_field)?;
 
     Ok(network)
 }
\ No newline at end of file
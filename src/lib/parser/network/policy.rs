@@ -0,0 +1,332 @@
+use toml::Value;
+use std::collections::BTreeMap;
+use crate::lib::helper::error::{
+    LError,
+    network::Error
+};
+use crate::lib::helper::toml::{
+    get_value_for_t,
+    get_value_for_t_lax
+};
+
+// Constant
+const INGRESS: &str = "Ingress";
+const EGRESS: &str = "Egress";
+
+#[derive(Debug, Clone, Default)]
+pub struct NetworkPolicy {
+    pub pod_selector: Option<BTreeMap<String, String>>,
+    pub default_deny: Vec<String>,
+    pub ingress: Option<Vec<PolicyRule>>,
+    pub egress: Option<Vec<PolicyRule>>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PolicyRule {
+    pub peers: Vec<Peer>,
+    pub ports: Vec<PolicyPort>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Peer {
+    pub pod_selector: Option<BTreeMap<String, String>>,
+    pub namespace_selector: Option<BTreeMap<String, String>>,
+    pub ip_block: Option<IpBlock>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct IpBlock {
+    pub cidr: String,
+    pub except: Vec<String>
+}
+
+/// Port
+///
+/// # Description
+/// Port of a rule either as a number or as a named port of the pod (i.e: "http")
+#[derive(Debug, Clone, PartialEq)]
+pub enum Port {
+    Number(i32),
+    Name(String)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PolicyPort {
+    pub protocol: Option<String>,
+    pub port: Option<Port>
+}
+
+impl NetworkPolicy {
+    /// New
+    ///
+    /// # Description
+    /// Create a new NetworkPolicy struct from the [policy] table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let pod_selector = get_value_for_t_lax::<BTreeMap<String, String>>(ast, "pod_selector");
+        let default_deny = get_default_deny(ast)?;
+
+        Ok(NetworkPolicy {
+            pod_selector,
+            default_deny,
+            ..Default::default()
+        })
+    }
+
+    /// Set Rules
+    ///
+    /// # Description
+    /// Set the ingress & egress rules. Each rule is a table which has the following definition
+    ///
+    /// [policy.ingress.<name>]
+    ///     from = [...]
+    /// [policy.egress.<name>]
+    ///     to = [...]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_rules(mut self, ast: &Value) -> Result<Self, LError> {
+        if let Some(ingress) = ast.get("ingress") {
+            self.ingress = Some(get_rules(ingress, "from")?);
+        }
+
+        if let Some(egress) = ast.get("egress") {
+            self.egress = Some(get_rules(egress, "to")?);
+        }
+
+        Ok(self)
+    }
+}
+
+/// Get Default Deny
+///
+/// # Description
+/// Retrieve the policy types which deny every traffic. The value could be "ingress", "egress" or "all"
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Vec<String>, LError>
+fn get_default_deny(ast: &Value) -> Result<Vec<String>, LError> {
+    let deny = match get_value_for_t_lax::<String>(ast, "default_deny") {
+        Some(d) => d,
+        None => return Ok(Vec::new())
+    };
+
+    match deny.to_lowercase().as_str() {
+        "ingress" => Ok(vec![INGRESS.to_owned()]),
+        "egress" => Ok(vec![EGRESS.to_owned()]),
+        "all" => Ok(vec![INGRESS.to_owned(), EGRESS.to_owned()]),
+        _ => Err(LError::from(Error::WrongDefaultDeny(deny)))
+    }
+}
+
+/// Get Rules
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `peer_key` - &str
+///
+/// # Return
+/// Result<Vec<PolicyRule>, LError>
+fn get_rules(ast: &Value, peer_key: &str) -> Result<Vec<PolicyRule>, LError> {
+    let rules = ast.as_table()
+        .ok_or_else(|| LError::from(Error::PolicyWrongType))?;
+
+    let mut policy_rules = Vec::new();
+    for (_, rule) in rules.into_iter() {
+        let peers = match rule.get(peer_key).and_then(|p| p.as_array()) {
+            Some(items) => items.iter()
+                .map(get_peer)
+                .collect::<Result<Vec<Peer>, LError>>()?,
+            None => Vec::new()
+        };
+
+        let ports = match rule.get("ports").and_then(|p| p.as_array()) {
+            Some(items) => items.iter()
+                .map(get_port)
+                .collect::<Result<Vec<PolicyPort>, LError>>()?,
+            None => Vec::new()
+        };
+
+        policy_rules.push(PolicyRule { peers, ports });
+    }
+
+    Ok(policy_rules)
+}
+
+/// Get Peer
+///
+/// # Description
+/// Retrieve a peer. An empty selector (i.e: namespace_selector = {}) select everything
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Peer, LError>
+fn get_peer(ast: &Value) -> Result<Peer, LError> {
+    let ip_block = match ast.get("ip_block") {
+        Some(block) => Some(IpBlock {
+            cidr: get_value_for_t::<String>(block, "cidr")
+                .map_err(|_| LError::from(Error::MissingCidr))?,
+            except: get_value_for_t_lax::<Vec<String>>(block, "except").unwrap_or_default()
+        }),
+        None => None
+    };
+
+    Ok(Peer {
+        pod_selector: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "pod_selector"),
+        namespace_selector: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "namespace_selector"),
+        ip_block
+    })
+}
+
+/// Get Port
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<PolicyPort, LError>
+fn get_port(ast: &Value) -> Result<PolicyPort, LError> {
+    let port = match ast.get("port") {
+        Some(Value::Integer(number)) => Some(Port::Number(*number as i32)),
+        Some(Value::String(name)) => Some(Port::Name(name.to_owned())),
+        Some(_) => return Err(LError::from(Error::WrongPolicyPort)),
+        None => None
+    };
+
+    Ok(PolicyPort {
+        protocol: get_value_for_t_lax::<String>(ast, "protocol"),
+        port
+    })
+}
+
+/// Get Network Policy
+///
+/// # Description
+/// Get the network policy. It has the following toml definition
+///
+/// <root>
+/// [policy]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<NetworkPolicy, LError>
+pub fn get_network_policy(ast: &Value) -> Result<NetworkPolicy, LError> {
+    NetworkPolicy::new(ast)?.set_rules(ast)
+}
+
+#[cfg(test)]
+mod test {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_network_policy() {
+        let template = "
+        [policy]
+            pod_selector = { tier = 'backend' }
+
+            [policy.ingress.frontend]
+                from = [
+                    { namespace_selector = { team = 'frontend' }, pod_selector = { app = 'web' } },
+                    { ip_block = { cidr = '10.0.0.0/8', except = ['10.0.1.0/24'] } }
+                ]
+                ports = [
+                    { protocol = 'TCP', port = 8080 },
+                    { port = 'metrics' }
+                ]
+
+            [policy.egress.dns]
+                to = [
+                    { namespace_selector = {} }
+                ]
+                ports = [
+                    { protocol = 'UDP', port = 53 }
+                ]
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let policy = get_network_policy(ast.get("policy").unwrap()).unwrap();
+
+        assert_eq!(policy.pod_selector.unwrap().get("tier").unwrap(), "backend");
+        assert!(policy.default_deny.is_empty());
+
+        let ingress = policy.ingress.unwrap();
+        let rule = ingress.first().unwrap();
+        assert_eq!(rule.peers.len(), 2);
+
+        let peer = rule.peers.first().unwrap();
+        assert_eq!(peer.namespace_selector.as_ref().unwrap().get("team").unwrap(), "frontend");
+        assert_eq!(peer.pod_selector.as_ref().unwrap().get("app").unwrap(), "web");
+
+        let block = rule.peers.last().unwrap().ip_block.as_ref().unwrap();
+        assert_eq!(block.cidr, "10.0.0.0/8");
+        assert_eq!(block.except, vec!["10.0.1.0/24"]);
+
+        assert_eq!(rule.ports.first().unwrap().port.as_ref().unwrap(), &Port::Number(8080));
+        assert_eq!(rule.ports.last().unwrap().port.as_ref().unwrap(), &Port::Name("metrics".to_owned()));
+
+        let egress = policy.egress.unwrap();
+        let dns = egress.first().unwrap().peers.first().unwrap();
+        assert!(dns.namespace_selector.as_ref().unwrap().is_empty());
+        assert!(dns.pod_selector.is_none());
+    }
+
+    #[test]
+    fn expect_to_parse_default_deny() {
+        let template = "
+        [policy]
+            default_deny = 'all'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let policy = get_network_policy(ast.get("policy").unwrap()).unwrap();
+
+        assert_eq!(policy.default_deny, vec!["Ingress", "Egress"]);
+        assert!(policy.ingress.is_none());
+    }
+
+    #[test]
+    fn expect_to_fail_wrong_default_deny() {
+        let template = "
+        [policy]
+            default_deny = 'everything'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let policy = get_network_policy(ast.get("policy").unwrap());
+
+        assert!(policy.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_cidr() {
+        let template = "
+        [policy]
+            [policy.ingress.office]
+                from = [
+                    { ip_block = { except = ['10.0.1.0/24'] } }
+                ]
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let policy = get_network_policy(ast.get("policy").unwrap());
+
+        assert!(policy.is_err());
+    }
+}
\ No newline at end of file
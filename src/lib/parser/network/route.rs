@@ -0,0 +1,278 @@
+use std::collections::BTreeMap;
+use toml::Value;
+use crate::lib::helper::error::{
+    LError,
+    network::Error
+};
+use crate::lib::helper::toml::{
+    get_value_for_t,
+    get_value_for_t_lax
+};
+use crate::lib::helper::conv::Convert;
+use super::backend::Backend;
+use super::policy::Port;
+
+// Constant
+const DEFAULT_PATH_TYPE: &str = "PathPrefix";
+
+/// HttpRoute
+///
+/// # Description
+/// HTTPRoute of the Gateway API. The rules are defined as named tables like the rules of an ingress
+#[derive(Debug, Clone, Default)]
+pub struct HttpRoute {
+    pub parents: Vec<ParentRef>,
+    pub hostnames: Vec<String>,
+    pub rules: Vec<RouteRule>
+}
+
+/// ParentRef
+///
+/// # Description
+/// Gateway which the route is attached to. The section is the name of a listener of the gateway
+#[derive(Debug, Clone, Default)]
+pub struct ParentRef {
+    pub name: String,
+    pub namespace: Option<String>,
+    pub section: Option<String>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RouteRule {
+    pub matches: Vec<RouteMatch>,
+    pub backends: Vec<BackendRef>,
+    pub request_headers: Option<HeaderModifier>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RouteMatch {
+    pub kind: String,
+    pub path: Option<String>,
+    pub headers: BTreeMap<String, String>,
+    pub method: Option<String>
+}
+
+/// BackendRef
+///
+/// # Description
+/// Backend of a rule. The traffic is split between the backends of a rule with the weight
+#[derive(Debug, Clone, Default)]
+pub struct BackendRef {
+    pub backend: Backend,
+    pub weight: Option<i32>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct HeaderModifier {
+    pub set: BTreeMap<String, String>,
+    pub add: BTreeMap<String, String>,
+    pub remove: Vec<String>
+}
+
+impl Convert for ParentRef {
+    fn convert(ast: &Value) -> Self {
+        ParentRef {
+            name: get_value_for_t::<String>(ast, "name").unwrap_or_default(),
+            namespace: get_value_for_t_lax::<String>(ast, "namespace"),
+            section: get_value_for_t_lax::<String>(ast, "section")
+        }
+    }
+}
+
+impl Convert for RouteMatch {
+    fn convert(ast: &Value) -> Self {
+        RouteMatch {
+            kind: get_value_for_t_lax::<String>(ast, "type")
+                .unwrap_or_else(|| DEFAULT_PATH_TYPE.to_owned()),
+            path: get_value_for_t_lax::<String>(ast, "path"),
+            headers: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "headers").unwrap_or_default(),
+            method: get_value_for_t_lax::<String>(ast, "method")
+        }
+    }
+}
+
+impl Convert for HeaderModifier {
+    fn convert(ast: &Value) -> Self {
+        HeaderModifier {
+            set: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "set").unwrap_or_default(),
+            add: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "add").unwrap_or_default(),
+            remove: get_value_for_t_lax::<Vec<String>>(ast, "remove").unwrap_or_default()
+        }
+    }
+}
+
+impl BackendRef {
+    /// New
+    ///
+    /// # Description
+    /// Create a new BackendRef. The port of a backend can't be a named port
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let backend = Backend::new(ast)?;
+        if let Port::Name(name) = &backend.port {
+            return Err(LError::from(Error::NamedBackendPort(name.to_owned())));
+        }
+
+        Ok(BackendRef {
+            backend,
+            weight: get_value_for_t_lax::<i32>(ast, "weight")
+        })
+    }
+}
+
+impl RouteRule {
+    /// New
+    ///
+    /// # Description
+    /// Create a new RouteRule from the [rules.<name>] table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let matches = ast.get("matches")
+            .and_then(|m| m.as_array())
+            .map(|m| m.iter().map(RouteMatch::convert).collect::<Vec<RouteMatch>>())
+            .unwrap_or_default();
+
+        let backends = match ast.get("backends").and_then(|b| b.as_array()) {
+            Some(b) => b.iter().map(BackendRef::new).collect::<Result<Vec<BackendRef>, LError>>()?,
+            None => Vec::new()
+        };
+
+        Ok(RouteRule {
+            matches,
+            backends,
+            request_headers: get_value_for_t_lax::<HeaderModifier>(ast, "request_headers")
+        })
+    }
+}
+
+/// Get Http Route
+///
+/// # Description
+/// Retrieve the http route. It has the following toml definition
+///
+/// [route]
+///     parents = [{ name = "<gateway>" }]
+///
+///     [route.rules.<name>]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<HttpRoute, LError>
+pub fn get_http_route(ast: &Value) -> Result<HttpRoute, LError> {
+    let parents = ast.get("parents")
+        .and_then(|p| p.as_array())
+        .map(|p| p.iter().map(ParentRef::convert).collect::<Vec<ParentRef>>())
+        .unwrap_or_default();
+
+    if parents.is_empty() || parents.iter().any(|p| p.name.is_empty()) {
+        return Err(LError::from(Error::MissingRouteParent));
+    }
+
+    let rules = match ast.get("rules").and_then(|r| r.as_table()) {
+        Some(table) => table
+            .values()
+            .map(RouteRule::new)
+            .collect::<Result<Vec<RouteRule>, LError>>()?,
+        None => Vec::new()
+    };
+
+    Ok(HttpRoute {
+        parents,
+        hostnames: get_value_for_t_lax::<Vec<String>>(ast, "hostnames").unwrap_or_default(),
+        rules
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_http_route() {
+        let template = "
+        [route]
+            parents = [
+                { name = 'gateway', namespace = 'infra', section = 'https' }
+            ]
+            hostnames = ['foo.bar.com']
+
+            [route.rules]
+                [route.rules.rusty]
+                    matches = [
+                        { path = '/api', headers = { 'x-version' = 'v2' }, method = 'GET' },
+                        { type = 'Exact', path = '/health' }
+                    ]
+                    backends = [
+                        { name = 'rusty', port = 8080, weight = 90 },
+                        { name = 'rusty-canary', port = 8080, weight = 10 }
+                    ]
+                    request_headers = { set = { 'x-env' = 'prod' }, remove = ['x-debug'] }
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let route = get_http_route(ast.get("route").unwrap()).unwrap();
+
+        let parent = route.parents.first().unwrap();
+        assert_eq!(parent.name, "gateway");
+        assert_eq!(parent.namespace.as_ref().unwrap(), "infra");
+        assert_eq!(parent.section.as_ref().unwrap(), "https");
+        assert_eq!(route.hostnames, vec!["foo.bar.com"]);
+
+        let rule = route.rules.first().unwrap();
+        let api = rule.matches.first().unwrap();
+        assert_eq!(api.kind, "PathPrefix");
+        assert_eq!(api.path.as_ref().unwrap(), "/api");
+        assert_eq!(api.headers.get("x-version").unwrap(), "v2");
+        assert_eq!(api.method.as_ref().unwrap(), "GET");
+        assert_eq!(rule.matches.last().unwrap().kind, "Exact");
+
+        let canary = rule.backends.last().unwrap();
+        assert_eq!(canary.backend.name, "rusty-canary");
+        assert_eq!(canary.backend.port, Port::Number(8080));
+        assert_eq!(canary.weight.unwrap(), 10);
+
+        let headers = rule.request_headers.as_ref().unwrap();
+        assert_eq!(headers.set.get("x-env").unwrap(), "prod");
+        assert!(headers.add.is_empty());
+        assert_eq!(headers.remove, vec!["x-debug"]);
+    }
+
+    #[test]
+    fn expect_to_fail_missing_parent() {
+        let template = "
+        [route]
+            hostnames = ['foo.bar.com']
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_http_route(ast.get("route").unwrap()).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_named_backend_port() {
+        let template = "
+        [route]
+            parents = [{ name = 'gateway' }]
+
+            [route.rules]
+                [route.rules.rusty]
+                    backends = [{ name = 'rusty', port = 'http' }]
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_http_route(ast.get("route").unwrap()).is_err());
+    }
+}
\ No newline at end of file
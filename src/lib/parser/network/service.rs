@@ -1,7 +1,9 @@
 use toml::Value;
-use std::convert::From;
+use std::convert::TryFrom;
 use std::collections::BTreeMap;
 use crate::lib::helper::error::LError;
+use crate::lib::helper::error::network::Error;
+use crate::lib::helper::conv::Convert;
 use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
 
 #[derive(Debug, Clone)]
@@ -9,17 +11,33 @@ pub struct Service {
     // We don't do a check on the kind here
     // This will be done by an other module
     pub kind: String,
-    pub ports: Option<BTreeMap<String, Port>>
+    pub ports: Option<BTreeMap<String, Port>>,
+    pub selector: Option<BTreeMap<String, String>>,
+    pub cluster_ip: Option<String>,
+    pub external_name: Option<String>,
+    pub session_affinity: Option<String>,
+    pub external_traffic_policy: Option<String>,
+    pub load_balancer_source_ranges: Option<Vec<String>>
 }
 
 #[derive(Debug, Clone)]
 pub struct Port {
     pub protocol: String,
     pub port: i64,
-    pub target_port: i64,
+    pub target_port: Option<TargetPort>,
     pub node_port: Option<i32>
 }
 
+/// TargetPort
+///
+/// # Description
+/// The target port could be a number or the name of a port of the pods
+#[derive(Debug, Clone, PartialEq)]
+pub enum TargetPort {
+    Number(i32),
+    Name(String)
+}
+
 impl Service {
     /// New
     ///
@@ -36,7 +54,13 @@ impl Service {
 
         Ok(Service {
             kind,
-            ports: None
+            ports: None,
+            selector: get_field::<BTreeMap<String, String>>(ast, "selector", is_string_table)?,
+            cluster_ip: get_field::<String>(ast, "cluster_ip", Value::is_str)?,
+            external_name: get_field::<String>(ast, "external_name", Value::is_str)?,
+            session_affinity: get_field::<String>(ast, "session_affinity", Value::is_str)?,
+            external_traffic_policy: get_field::<String>(ast, "external_traffic_policy", Value::is_str)?,
+            load_balancer_source_ranges: get_field::<Vec<String>>(ast, "load_balancer_source_ranges", is_string_array)?
         })
     }
 
@@ -48,32 +72,108 @@ impl Service {
     /// # Arguments
     /// * `mut self` - Self
     /// * `ast` - &Value
-    fn set_ports(mut self, ast: &Value) -> Self {
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_ports(mut self, ast: &Value) -> Result<Self, LError> {
         let ports_field = ast.get("ports");
         if let Some(p) = ports_field {
-            self.ports = get_ports(p);
+            self.ports = get_ports(p)?;
         }
 
-        self
+        Ok(self)
     }
 }
 
-impl From<Value> for Port {
-    fn from(ast: Value) -> Self {
-        let protocol = get_value_for_t::<String>(&ast, "protocol").unwrap_or_default();
-        let port = get_value_for_t::<i64>(&ast, "port").unwrap_or(0);
-        let target_port = get_value_for_t::<i64>(&ast, "target_port").unwrap_or(0);
-        let node_port = get_value_for_t_lax::<i32>(&ast, "node_port");
+impl Port {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Port struct
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let protocol = get_value_for_t::<String>(ast, "protocol").unwrap_or_default();
+        let port = get_value_for_t::<i64>(ast, "port").unwrap_or(0);
+        let target_port = match ast.get("target_port") {
+            Some(Value::Integer(number)) => {
+                let number = i32::try_from(*number)
+                    .map_err(|_| LError::from(Error::WrongTargetPort(number.to_string())))?;
+
+                Some(TargetPort::Number(number))
+            },
+            Some(Value::String(name)) => Some(TargetPort::Name(name.to_owned())),
+            _ => None
+        };
+        let node_port = get_value_for_t_lax::<i32>(ast, "node_port");
 
-        Port {
+        Ok(Port {
             protocol,
             port,
             target_port,
             node_port
-        }
+        })
+    }
+}
+
+/// Get Field
+///
+/// # Description
+/// Retrieve an optional field of the service and check that its value has the expected type
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `key` - &str
+/// * `expected` - fn(&Value) -> bool
+///
+/// # Return
+/// Result<Option<T>, LError>
+fn get_field<T: Convert>(ast: &Value, key: &str, expected: fn(&Value) -> bool) -> Result<Option<T>, LError> {
+    match ast.get(key) {
+        Some(value) if expected(value) => Ok(Some(T::convert(value))),
+        Some(_) => Err(LError::from(Error::WrongServiceField(key.to_owned()))),
+        None => Ok(None)
     }
 }
 
+/// Is String Table
+///
+/// # Description
+/// Check that the value is a table which only contains strings
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// bool
+fn is_string_table(value: &Value) -> bool {
+    value
+        .as_table()
+        .map(|table| table.values().all(Value::is_str))
+        .unwrap_or(false)
+}
+
+/// Is String Array
+///
+/// # Description
+/// Check that the value is an array which only contains strings
+///
+/// # Arguments
+/// * `value` - &Value
+///
+/// # Return
+/// bool
+fn is_string_array(value: &Value) -> bool {
+    value
+        .as_array()
+        .map(|array| array.iter().all(Value::is_str))
+        .unwrap_or(false)
+}
+
 /// Get Ports
 ///
 /// # Description
@@ -85,17 +185,17 @@ impl From<Value> for Port {
 ///   [service.ports]
 ///
 ///     [service.ports.<xx>]
-fn get_ports(past: &Value) -> Option<BTreeMap<String, Port>> {
-    let map = past.as_table();
-    let map = map.as_ref()?;
+fn get_ports(past: &Value) -> Result<Option<BTreeMap<String, Port>>, LError> {
+    let map = match past.as_table() {
+        Some(map) => map,
+        None => return Ok(None)
+    };
     let btree = map
         .into_iter()
-        .map(|(k, v)| {
-            (k.to_owned(), Port::from(v.to_owned()))
-        })
-        .collect::<BTreeMap<String, Port>>();
+        .map(|(k, v)| Ok((k.to_owned(), Port::new(v)?)))
+        .collect::<Result<BTreeMap<String, Port>, LError>>()?;
 
-    Some(btree)
+    Ok(Some(btree))
 }
 
 /// Get Service
@@ -109,7 +209,7 @@ fn get_ports(past: &Value) -> Option<BTreeMap<String, Port>> {
 /// # Return
 /// Result<Service, LError>
 pub fn get_service(ast: &Value) -> Result<Service, LError> {
-    let service = Service::new(ast)?.set_ports(ast);
+    let service = Service::new(ast)?.set_ports(ast)?;
 
     Ok(service)
 }
@@ -119,6 +219,7 @@ mod test {
     use toml::Value;
 
     use super::get_service;
+    use super::TargetPort;
     
     #[test]
     fn expect_to_parse_service_type() {
@@ -161,7 +262,7 @@ mod test {
 
         assert_eq!(http.protocol, "TCP");
         assert_eq!(http.port, 80);
-        assert_eq!(http.target_port, 90);
+        assert_eq!(http.target_port.as_ref().unwrap(), &TargetPort::Number(90));
         assert_eq!(http.node_port.unwrap(), 30310);
     }
 
@@ -177,4 +278,89 @@ mod test {
         let service = get_service(&service_ast);
         assert!(service.is_err());
     }
+
+    #[test]
+    fn expect_to_parse_advanced_service() {
+        let template = "
+            [service]
+                type = 'LoadBalancer'
+                selector = { app = 'rusty' }
+                session_affinity = 'ClientIP'
+                external_traffic_policy = 'Local'
+                load_balancer_source_ranges = ['10.0.0.0/8']
+
+                [service.ports]
+                    [service.ports.http]
+                        protocol = 'TCP'
+                        port = 80
+                        target_port = 'http'
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let service_ast = ast.get("service").unwrap();
+
+        let service = get_service(service_ast).unwrap();
+        assert_eq!(service.selector.unwrap().get("app").unwrap(), "rusty");
+        assert_eq!(service.session_affinity.unwrap(), "ClientIP");
+        assert_eq!(service.external_traffic_policy.unwrap(), "Local");
+        assert_eq!(service.load_balancer_source_ranges.unwrap(), vec!["10.0.0.0/8"]);
+
+        let ports = service.ports.unwrap();
+        let http = ports.get("http").unwrap();
+        assert_eq!(http.target_port.as_ref().unwrap(), &TargetPort::Name("http".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_not_parse_overflowing_target_port() {
+        let template = "
+            [service]
+                type = 'ClusterIP'
+
+                [service.ports]
+                    [service.ports.http]
+                        protocol = 'TCP'
+                        port = 80
+                        target_port = 3000000000
+        ";
+
+        let ast = template.parse::<Value>().unwrap();
+        let service_ast = ast.get("service").unwrap();
+
+        let service = get_service(service_ast);
+        assert!(service.is_err());
+    }
+
+    #[test]
+    fn expect_to_not_parse_malformed_service_fields() {
+        let templates = vec![
+            "
+            [service]
+                type = 'ClusterIP'
+                selector = 'app'
+            ",
+            "
+            [service]
+                type = 'ClusterIP'
+                selector = { app = 1 }
+            ",
+            "
+            [service]
+                type = 'LoadBalancer'
+                session_affinity = true
+            ",
+            "
+            [service]
+                type = 'LoadBalancer'
+                load_balancer_source_ranges = '10.0.0.0/8'
+            "
+        ];
+
+        for template in templates {
+            let ast = template.parse::<Value>().unwrap();
+            let service_ast = ast.get("service").unwrap();
+
+            let service = get_service(service_ast);
+            assert!(service.is_err());
+        }
+    }
 }
\ No newline at end of file
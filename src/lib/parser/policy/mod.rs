@@ -0,0 +1,178 @@
+use std::convert::TryFrom;
+use toml::Value;
+use crate::lib::helper::error::{
+    LError,
+    policy::Error
+};
+
+/// Budget
+///
+/// # Description
+/// Number of pods either as an integer or as a percentage (i.e: "50%")
+#[derive(Debug, Clone, PartialEq)]
+pub enum Budget {
+    Number(i32),
+    Percent(String)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Disruption {
+    pub min_available: Option<Budget>,
+    pub max_unavailable: Option<Budget>
+}
+
+impl Disruption {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Disruption struct from the [disruption] table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let min_available = get_budget(ast, "min_available")?;
+        let max_unavailable = get_budget(ast, "max_unavailable")?;
+
+        match (&min_available, &max_unavailable) {
+            (Some(_), Some(_)) => Err(LError::from(Error::BothBudgetSet)),
+            (None, None) => Err(LError::from(Error::MissingBudget)),
+            _ => Ok(Disruption {
+                min_available,
+                max_unavailable
+            })
+        }
+    }
+}
+
+/// Get Budget
+///
+/// # Description
+/// Retrieve a budget which could be an integer or a percentage
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `key` - &str
+///
+/// # Return
+/// Result<Option<Budget>, LError>
+pub fn get_budget(ast: &Value, key: &str) -> Result<Option<Budget>, LError> {
+    match ast.get(key) {
+        Some(Value::Integer(number)) => i32::try_from(*number)
+            .map(|number| Some(Budget::Number(number)))
+            .map_err(|_| LError::from(Error::WrongBudget(key.to_owned()))),
+        Some(Value::String(percent)) if percent.ends_with('%') => Ok(Some(Budget::Percent(percent.to_owned()))),
+        Some(_) => Err(LError::from(Error::WrongBudget(key.to_owned()))),
+        None => Ok(None)
+    }
+}
+
+/// Get Disruption
+///
+/// # Description
+/// Retrieve the disruption budget. It has the following toml definition
+///
+/// <root>
+/// [disruption]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Disruption, LError>
+pub fn get_disruption(ast: &Value) -> Result<Disruption, LError> {
+    let disruption = ast.get("disruption")
+        .ok_or_else(|| LError::from(Error::DisruptionNotExist))?;
+
+    Disruption::new(disruption)
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::Budget;
+
+    #[test]
+    fn expect_to_parse_disruption() {
+        let template = r#"
+        kind = "policy::pdb"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            min_available = 2
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let disruption = super::get_disruption(&ast).unwrap();
+
+        assert_eq!(disruption.min_available.unwrap(), Budget::Number(2));
+        assert!(disruption.max_unavailable.is_none());
+    }
+
+    #[test]
+    fn expect_to_parse_percentage() {
+        let template = r#"
+        kind = "policy::pdb"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            max_unavailable = "25%"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let disruption = super::get_disruption(&ast).unwrap();
+
+        assert_eq!(disruption.max_unavailable.unwrap(), Budget::Percent("25%".to_owned()));
+    }
+
+    #[test]
+    fn expect_to_fail_wrong_budget() {
+        let template = r#"
+        kind = "policy::pdb"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            max_unavailable = "two"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let disruption = super::get_disruption(&ast);
+
+        assert!(disruption.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_both_budget() {
+        let template = r#"
+        kind = "policy::pdb"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            min_available = 1
+            max_unavailable = 1
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let disruption = super::get_disruption(&ast);
+
+        assert!(disruption.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_overflowing_budget() {
+        let template = r#"
+        kind = "policy::pdb"
+        metadata = { name = "nginx" }
+
+        [disruption]
+            min_available = 3000000000
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let disruption = super::get_disruption(&ast);
+
+        assert!(disruption.is_err());
+    }
+}
\ No newline at end of file
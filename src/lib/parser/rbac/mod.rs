@@ -0,0 +1,298 @@
+use toml::Value;
+use crate::lib::helper::error::{
+    LError,
+    rbac::Error
+};
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
+use crate::lib::helper::conv::Convert;
+
+#[derive(Debug, Clone, Default)]
+pub struct Rbac {
+    pub service_account: Option<ServiceAccount>,
+    pub rules: Option<Vec<Rule>>,
+    pub binding: Option<Binding>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ServiceAccount {
+    pub automount_token: Option<bool>,
+    pub image_pull_secrets: Option<Vec<String>>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Rule {
+    pub api_groups: Vec<String>,
+    pub resources: Vec<String>,
+    pub verbs: Vec<String>,
+    pub resource_names: Vec<String>,
+    pub non_resource_urls: Vec<String>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Binding {
+    pub role: RoleRef,
+    pub subjects: Vec<Subject>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RoleRef {
+    pub kind: Option<String>,
+    pub name: String
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Subject {
+    pub kind: String,
+    pub name: String,
+    pub namespace: Option<String>
+}
+
+impl Convert for ServiceAccount {
+    fn convert(ast: &Value) -> Self {
+        ServiceAccount {
+            automount_token: get_value_for_t_lax::<bool>(ast, "automount_token"),
+            image_pull_secrets: get_value_for_t_lax::<Vec<String>>(ast, "image_pull_secrets")
+        }
+    }
+}
+
+impl Convert for Rule {
+    fn convert(ast: &Value) -> Self {
+        Rule {
+            api_groups: get_value_for_t_lax::<Vec<String>>(ast, "api_groups").unwrap_or_default(),
+            resources: get_value_for_t_lax::<Vec<String>>(ast, "resources").unwrap_or_default(),
+            verbs: get_value_for_t_lax::<Vec<String>>(ast, "verbs").unwrap_or_default(),
+            resource_names: get_value_for_t_lax::<Vec<String>>(ast, "resource_names").unwrap_or_default(),
+            non_resource_urls: get_value_for_t_lax::<Vec<String>>(ast, "non_resource_urls").unwrap_or_default()
+        }
+    }
+}
+
+impl Subject {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Subject. The kind and the name of a subject are required
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let kind = get_value_for_t::<String>(ast, "kind")
+            .map_err(|_| LError::from(Error::MissingSubjectField("kind".to_owned())))?;
+        let name = get_value_for_t::<String>(ast, "name")
+            .map_err(|_| LError::from(Error::MissingSubjectField("name".to_owned())))?;
+
+        Ok(Subject {
+            kind,
+            name,
+            namespace: get_value_for_t_lax::<String>(ast, "namespace")
+        })
+    }
+}
+
+impl Binding {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Binding from the [binding] table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let role = ast.get("role")
+            .ok_or_else(|| LError::from(Error::MissingRole))?;
+
+        let name = get_value_for_t::<String>(role, "name")
+            .map_err(|_| LError::from(Error::MissingRole))?;
+
+        let subjects = match ast.get("subjects").and_then(|s| s.as_array()) {
+            Some(arr) => arr.iter().map(Subject::new).collect::<Result<Vec<Subject>, LError>>()?,
+            None => Vec::new()
+        };
+
+        Ok(Binding {
+            role: RoleRef {
+                kind: get_value_for_t_lax::<String>(role, "kind"),
+                name
+            },
+            subjects
+        })
+    }
+}
+
+/// Get Rules
+///
+/// # Description
+/// Retrieve the rules. It has the following toml definition
+///
+/// [rules]
+///
+///   [rules.<name>]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Option<Vec<Rule>>
+fn get_rules(ast: &Value) -> Option<Vec<Rule>> {
+    let table = ast.get("rules")?.as_table()?;
+    let rules = table
+        .values()
+        .map(Rule::convert)
+        .collect::<Vec<Rule>>();
+
+    Some(rules)
+}
+
+/// Get Rbac
+///
+/// # Description
+/// Retrieve the rbac definition of a template. The definition could contain
+///     - [service_account]
+///     - [rules]
+///     - [binding]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Rbac, LError>
+pub fn get_rbac(ast: &Value) -> Result<Rbac, LError> {
+    let binding = match ast.get("binding") {
+        Some(b) => Some(Binding::new(b)?),
+        None => None
+    };
+
+    Ok(Rbac {
+        service_account: ast.get("service_account").map(ServiceAccount::convert),
+        rules: get_rules(ast),
+        binding
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+
+    #[test]
+    fn expect_to_parse_rules() {
+        let template = r#"
+        kind = "rbac::role"
+        metadata = { name = "pod-reader" }
+
+        [rules]
+            [rules.pods]
+                api_groups = [""]
+                resources = ["pods", "pods/log"]
+                verbs = ["get", "list", "watch"]
+
+            [rules.deployments]
+                api_groups = ["apps"]
+                resources = ["deployments"]
+                resource_names = ["nginx"]
+                verbs = ["get"]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let rbac = super::get_rbac(&ast).unwrap();
+        let rules = rbac.rules.unwrap();
+
+        assert_eq!(rules.len(), 2);
+
+        let pods = rules.first().unwrap();
+        assert_eq!(pods.resources, vec!["pods", "pods/log"]);
+        assert_eq!(pods.verbs, vec!["get", "list", "watch"]);
+
+        let deployments = rules.last().unwrap();
+        assert_eq!(deployments.api_groups, vec!["apps"]);
+        assert_eq!(deployments.resource_names, vec!["nginx"]);
+    }
+
+    #[test]
+    fn expect_to_parse_binding() {
+        let template = r#"
+        kind = "rbac::rolebinding"
+        metadata = { name = "read-pods" }
+
+        [binding]
+            role = { kind = "Role", name = "pod-reader" }
+            subjects = [
+                { kind = "ServiceAccount", name = "nginx", namespace = "default" },
+                { kind = "User", name = "jane" }
+            ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let rbac = super::get_rbac(&ast).unwrap();
+        let binding = rbac.binding.unwrap();
+
+        assert_eq!(binding.role.kind.unwrap(), "Role");
+        assert_eq!(binding.role.name, "pod-reader");
+        assert_eq!(binding.subjects.len(), 2);
+        assert_eq!(binding.subjects.first().unwrap().namespace.as_ref().unwrap(), "default");
+        assert!(binding.subjects.last().unwrap().namespace.is_none());
+    }
+
+    #[test]
+    fn expect_to_parse_service_account() {
+        let template = r#"
+        kind = "rbac::serviceaccount"
+        metadata = { name = "nginx" }
+
+        [service_account]
+            automount_token = false
+            image_pull_secrets = ["regcred"]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let rbac = super::get_rbac(&ast).unwrap();
+        let sa = rbac.service_account.unwrap();
+
+        assert!(!sa.automount_token.unwrap());
+        assert_eq!(sa.image_pull_secrets.unwrap(), vec!["regcred"]);
+    }
+
+    #[test]
+    fn expect_to_fail_missing_role() {
+        let template = r#"
+        kind = "rbac::rolebinding"
+        metadata = { name = "read-pods" }
+
+        [binding]
+            subjects = [
+                { kind = "User", name = "jane" }
+            ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let rbac = super::get_rbac(&ast);
+
+        assert!(rbac.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_subject_kind() {
+        let template = r#"
+        kind = "rbac::rolebinding"
+        metadata = { name = "read-pods" }
+
+        [binding]
+            role = { kind = "Role", name = "pod-reader" }
+            subjects = [
+                { name = "jane" }
+            ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let rbac = super::get_rbac(&ast);
+
+        assert!(rbac.is_err());
+        assert_eq!(rbac.unwrap_err().message, "kind is required in a subject of the [binding] table");
+    }
+}
\ No newline at end of file
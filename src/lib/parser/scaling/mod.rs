@@ -0,0 +1,274 @@
+use toml::Value;
+use crate::lib::helper::error::{
+    LError,
+    scaling::Error
+};
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
+use crate::lib::helper::conv::Convert;
+
+// Constant
+const DEFAULT_TARGET_KIND: &str = "deployment";
+
+#[derive(Debug, Clone, Default)]
+pub struct Scaling {
+    pub target: Target,
+    pub min_replicas: Option<i32>,
+    pub max_replicas: i32,
+    pub cpu: Option<i32>,
+    pub memory: Option<i32>,
+    pub scale_up: Option<Rules>,
+    pub scale_down: Option<Rules>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Target {
+    pub kind: String,
+    pub name: Option<String>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Rules {
+    pub stabilization_window: Option<i32>,
+    pub select_policy: Option<String>,
+    pub policies: Vec<Policy>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Policy {
+    pub kind: String,
+    pub value: i32,
+    pub period: i32
+}
+
+impl Convert for Target {
+    fn convert(ast: &Value) -> Self {
+        let kind = get_value_for_t_lax::<String>(ast, "kind")
+            .unwrap_or_else(|| DEFAULT_TARGET_KIND.to_owned());
+        let name = get_value_for_t_lax::<String>(ast, "name");
+
+        Target {
+            kind,
+            name
+        }
+    }
+}
+
+impl Policy {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Policy. The type, value and period of a policy are required
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let kind = get_value_for_t::<String>(ast, "type")
+            .map_err(|_| LError::from(Error::MissingPolicyField("type".to_owned())))?;
+        let value = get_value_for_t::<i32>(ast, "value")
+            .map_err(|_| LError::from(Error::MissingPolicyField("value".to_owned())))?;
+        let period = get_value_for_t::<i32>(ast, "period")
+            .map_err(|_| LError::from(Error::MissingPolicyField("period".to_owned())))?;
+
+        Ok(Policy {
+            kind,
+            value,
+            period
+        })
+    }
+}
+
+impl Rules {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Rules from the scale_up or scale_down table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let stabilization_window = get_value_for_t_lax::<i32>(ast, "stabilization_window");
+        let select_policy = get_value_for_t_lax::<String>(ast, "select_policy");
+        let policies = match ast.get("policies").and_then(|p| p.as_array()) {
+            Some(arr) => arr.iter().map(Policy::new).collect::<Result<Vec<Policy>, LError>>()?,
+            None => Vec::new()
+        };
+
+        Ok(Rules {
+            stabilization_window,
+            select_policy,
+            policies
+        })
+    }
+}
+
+impl Scaling {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Scaling struct from the [autoscaling] table
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let max_replicas = get_value_for_t::<i32>(ast, "max_replicas")
+            .map_err(|_| LError::from(Error::MissingMaxReplicas))?;
+
+        let target = ast.get("target")
+            .map(Target::convert)
+            .unwrap_or_else(|| Target {
+                kind: DEFAULT_TARGET_KIND.to_owned(),
+                name: None
+            });
+
+        Ok(Scaling {
+            target,
+            min_replicas: get_value_for_t_lax::<i32>(ast, "min_replicas"),
+            max_replicas,
+            cpu: get_value_for_t_lax::<i32>(ast, "cpu"),
+            memory: get_value_for_t_lax::<i32>(ast, "memory"),
+            scale_up: ast.get("scale_up").map(Rules::new).transpose()?,
+            scale_down: ast.get("scale_down").map(Rules::new).transpose()?
+        })
+    }
+}
+
+/// Get Scaling
+///
+/// # Description
+/// Retrieve the autoscaling definition. It has the following toml definition
+///
+/// <root>
+/// [autoscaling]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Scaling, LError>
+pub fn get_scaling(ast: &Value) -> Result<Scaling, LError> {
+    let autoscaling = ast.get("autoscaling")
+        .ok_or_else(|| LError::from(Error::AutoscalingNotExist))?;
+
+    Scaling::new(autoscaling)
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+
+    #[test]
+    fn expect_to_parse_autoscaling() {
+        let template = r#"
+        kind = "scaling::hpa"
+        metadata = { name = "nginx" }
+
+        [autoscaling]
+            target = { kind = "statefulset", name = "redis" }
+            min_replicas = 2
+            max_replicas = 10
+            cpu = 80
+            memory = 70
+
+            [autoscaling.scale_up]
+                stabilization_window = 0
+                select_policy = "Max"
+                policies = [
+                    { type = "Percent", value = 100, period = 15 },
+                    { type = "Pods", value = 4, period = 15 }
+                ]
+
+            [autoscaling.scale_down]
+                stabilization_window = 300
+                policies = [
+                    { type = "Percent", value = 10, period = 60 }
+                ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let scaling = super::get_scaling(&ast).unwrap();
+
+        assert_eq!(scaling.target.kind, "statefulset");
+        assert_eq!(scaling.target.name.unwrap(), "redis");
+        assert_eq!(scaling.min_replicas.unwrap(), 2);
+        assert_eq!(scaling.max_replicas, 10);
+        assert_eq!(scaling.cpu.unwrap(), 80);
+        assert_eq!(scaling.memory.unwrap(), 70);
+
+        let scale_up = scaling.scale_up.unwrap();
+        assert_eq!(scale_up.select_policy.unwrap(), "Max");
+        assert_eq!(scale_up.policies.len(), 2);
+
+        let policy = scale_up.policies.last().unwrap();
+        assert_eq!(policy.kind, "Pods");
+        assert_eq!(policy.value, 4);
+        assert_eq!(policy.period, 15);
+
+        let scale_down = scaling.scale_down.unwrap();
+        assert_eq!(scale_down.stabilization_window.unwrap(), 300);
+    }
+
+    #[test]
+    fn expect_to_use_default_target() {
+        let template = r#"
+        kind = "scaling::hpa"
+        metadata = { name = "nginx" }
+
+        [autoscaling]
+            max_replicas = 5
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let scaling = super::get_scaling(&ast).unwrap();
+
+        assert_eq!(scaling.target.kind, "deployment");
+        assert!(scaling.target.name.is_none());
+        assert!(scaling.scale_up.is_none());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_max_replicas() {
+        let template = r#"
+        kind = "scaling::hpa"
+        metadata = { name = "nginx" }
+
+        [autoscaling]
+            min_replicas = 2
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let scaling = super::get_scaling(&ast);
+
+        assert!(scaling.is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_policy_field() {
+        let template = r#"
+        kind = "scaling::hpa"
+        metadata = { name = "nginx" }
+
+        [autoscaling]
+            max_replicas = 5
+
+            [autoscaling.scale_up]
+                policies = [
+                    { type = "Percent", period = 15 }
+                ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let scaling = super::get_scaling(&ast);
+
+        assert!(scaling.is_err());
+        assert_eq!(scaling.unwrap_err().message, "value is required in a scaling policy");
+    }
+}
\ No newline at end of file
@@ -3,6 +3,11 @@ use super::network::{Network, get_network};
 use super::workload::{Workload, get_workload};
 use super::crd::{CustomCrd, get_custom_crd};
 use super::env::{Env, get_env};
+use super::scaling::{Scaling, get_scaling};
+use super::policy::{Disruption, get_disruption};
+use super::rbac::{Rbac, get_rbac};
+use super::cluster::{Cluster, get_cluster};
+use super::storage::{Storage, get_storage};
 use crate::lib::helper::error::LError;
 use super::Kind;
 
@@ -19,6 +24,11 @@ pub struct Spec {
     pub network: Option<Network>,
     pub crd: Option<CustomCrd>,
     pub env: Option<Env>,
+    pub scaling: Option<Scaling>,
+    pub disruption: Option<Disruption>,
+    pub rbac: Option<Rbac>,
+    pub cluster: Option<Cluster>,
+    pub storage: Option<Storage>,
     pub error: Option<LError>
 }
 
@@ -54,6 +64,36 @@ pub fn get_spec(ast: &Value, kind: &Kind) -> Spec {
                 Err(err) => spec.error = Some(err)
             }
         },
+        Kind::Scaling(_) => {
+            match get_scaling(ast) {
+                Ok(res) => spec.scaling = Some(res),
+                Err(err) => spec.error = Some(err)
+            }
+        },
+        Kind::Policy(_) => {
+            match get_disruption(ast) {
+                Ok(res) => spec.disruption = Some(res),
+                Err(err) => spec.error = Some(err)
+            }
+        },
+        Kind::Rbac(_) => {
+            match get_rbac(ast) {
+                Ok(res) => spec.rbac = Some(res),
+                Err(err) => spec.error = Some(err)
+            }
+        },
+        Kind::Cluster(_) => {
+            match get_cluster(ast) {
+                Ok(res) => spec.cluster = Some(res),
+                Err(err) => spec.error = Some(err)
+            }
+        },
+        Kind::Storage(_) => {
+            match get_storage(ast) {
+                Ok(res) => spec.storage = Some(res),
+                Err(err) => spec.error = Some(err)
+            }
+        },
         Kind::Env(_) => {
             if let Some(e) = get_env(ast) {
                 spec.env = Some(e)
@@ -0,0 +1,177 @@
+use toml::Value;
+use std::collections::BTreeMap;
+use crate::lib::helper::error::{
+    LError,
+    storage::Error
+};
+use crate::lib::helper::toml::get_value_for_t_lax;
+use super::volume::{VolumeClaimTemplates, get_volume_claim};
+
+// Constant
+const CLAIM_NAME: &str = "claim";
+
+#[derive(Debug, Clone, Default)]
+pub struct Storage {
+    pub claim: Option<VolumeClaimTemplates>,
+    pub class: Option<StorageClass>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StorageClass {
+    pub provisioner: String,
+    pub parameters: Option<BTreeMap<String, String>>,
+    pub reclaim_policy: Option<String>,
+    pub volume_binding_mode: Option<String>,
+    pub allow_volume_expansion: Option<bool>,
+    pub mount_options: Option<Vec<String>>
+}
+
+impl Storage {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Storage struct
+    ///
+    /// # Return
+    /// Self
+    fn new() -> Self {
+        Storage::default()
+    }
+
+    /// Set Claim
+    ///
+    /// # Description
+    /// Set the persistent volume claim. The [claim] table use the same definition as a [volume_claims.<name>] table
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Self
+    fn set_claim(mut self, ast: &Value) -> Self {
+        if let Some(claim) = ast.get("claim") {
+            self.claim = Some(get_volume_claim(claim, CLAIM_NAME));
+        }
+
+        self
+    }
+
+    /// Set Class
+    ///
+    /// # Description
+    /// Set the storage class. It has the following toml definition
+    ///
+    /// <root>
+    /// [storage_class]
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_class(mut self, ast: &Value) -> Result<Self, LError> {
+        let class = match ast.get("storage_class") {
+            Some(c) => c,
+            None => return Ok(self)
+        };
+
+        let provisioner = get_value_for_t_lax::<String>(class, "provisioner")
+            .ok_or_else(|| LError::from(Error::MissingProvisioner))?;
+
+        self.class = Some(StorageClass {
+            provisioner,
+            parameters: get_value_for_t_lax::<BTreeMap<String, String>>(class, "parameters"),
+            reclaim_policy: get_value_for_t_lax::<String>(class, "reclaim_policy"),
+            volume_binding_mode: get_value_for_t_lax::<String>(class, "volume_binding_mode"),
+            allow_volume_expansion: get_value_for_t_lax::<bool>(class, "allow_volume_expansion"),
+            mount_options: get_value_for_t_lax::<Vec<String>>(class, "mount_options")
+        });
+
+        Ok(self)
+    }
+}
+
+/// Get Storage
+///
+/// # Description
+/// Retrieve the storage resources of a template
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Storage, LError>
+pub fn get_storage(ast: &Value) -> Result<Storage, LError> {
+    Storage::new()
+        .set_claim(ast)
+        .set_class(ast)
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_claim() {
+        let template = r#"
+        kind = "storage::pvc"
+        name = "data"
+        metadata = { name = "data" }
+
+        [claim]
+            access_modes = ["ReadWriteOnce"]
+            class_name = "ssd"
+            resources_request = [
+                { key_name = "storage", value = "10Gi" }
+            ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let claim = get_storage(&ast).unwrap().claim.unwrap();
+
+        let desc = claim.description.unwrap();
+        assert_eq!(desc.access_modes.unwrap(), vec!["ReadWriteOnce"]);
+        assert_eq!(desc.class_name.unwrap(), "ssd");
+
+        let request = claim.resources.unwrap().request.unwrap();
+        assert_eq!(request.get("storage").unwrap(), "10Gi");
+    }
+
+    #[test]
+    fn expect_to_parse_storage_class() {
+        let template = r#"
+        kind = "storage::class"
+        name = "ssd"
+        metadata = { name = "ssd" }
+
+        [storage_class]
+            provisioner = "kubernetes.io/gce-pd"
+            parameters = { type = "pd-ssd" }
+            reclaim_policy = "Retain"
+            allow_volume_expansion = true
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let class = get_storage(&ast).unwrap().class.unwrap();
+
+        assert_eq!(class.provisioner, "kubernetes.io/gce-pd");
+        assert_eq!(class.parameters.unwrap().get("type").unwrap(), "pd-ssd");
+        assert_eq!(class.reclaim_policy.unwrap(), "Retain");
+        assert!(class.allow_volume_expansion.unwrap());
+        assert!(class.volume_binding_mode.is_none());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_provisioner() {
+        let template = r#"
+        [storage_class]
+            reclaim_policy = "Retain"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_storage(&ast).is_err());
+    }
+}
\ No newline at end of file
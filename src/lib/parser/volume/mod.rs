@@ -31,17 +31,20 @@ pub struct VolumeResources {
 #[derive(Debug, Clone, Default, Deserialize)]
 pub struct DataSource {
     pub name: Option<String>,
-    pub kind: Option<String>
+    pub kind: Option<String>,
+    pub api_group: Option<String>
 }
 
 impl Convert for DataSource {
     fn convert(v: &Value) -> Self {
         let name = get_value_for_t_lax::<String>(v, "name");
         let kind = get_value_for_t_lax::<String>(v, "kind");
+        let api_group = get_value_for_t_lax::<String>(v, "api_group");
 
         DataSource {
             name,
-            kind
+            kind,
+            api_group
         }
     }
 }
@@ -152,6 +155,23 @@ fn get_hmap_from_vec_toml(ast: &Value, key: &str) -> Option<HashMap<String, Stri
     Some(map)
 }
 
+/// Get Volume Claim
+///
+/// # Description
+/// Retrieve a single volume claim from a table which use the volume_claims definition
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `name` - &str
+///
+/// # Return
+/// VolumeClaimTemplates
+pub fn get_volume_claim(ast: &Value, name: &str) -> VolumeClaimTemplates {
+    VolumeClaimTemplates::new(ast, name)
+        .set_description(ast)
+        .set_resources(ast)
+}
+
 /// Get Volumes From Toml Tables
 ///
 /// # Description
@@ -165,10 +185,7 @@ fn get_hmap_from_vec_toml(ast: &Value, key: &str) -> Option<HashMap<String, Stri
 pub fn get_volumes_from_toml_tables(m: &Map<String, Value>) -> Option<HashMap<String, VolumeClaimTemplates>> {
     let mut volumes = HashMap::new();
     for (name, items) in m.into_iter() {
-        let volume = VolumeClaimTemplates::new(items, name)
-            .set_description(items)
-            .set_resources(items);
-
+        let volume = get_volume_claim(items, name);
         volumes.insert(name.to_owned(), volume);
     }
 
@@ -0,0 +1,66 @@
+use toml::Value;
+use crate::lib::helper::toml::get_value_for_t_lax;
+use crate::lib::helper::error::LError;
+use super::probes::{ProbeHttpGet, check_single_handler};
+
+// Constant
+// Actions of a lifecycle hook. Only one of them could be set
+const HOOK_HANDLERS: [&str; 3] = ["exec", "http_get", "sleep"];
+
+#[derive(Debug, Default, Clone)]
+pub struct Lifecycle {
+    pub post_start: Option<Handler>,
+    pub pre_stop: Option<Handler>
+}
+
+/// Handler
+///
+/// # Description
+/// Action run by a lifecycle hook. Only one of exec, http_get or sleep should be set
+#[derive(Debug, Default, Clone)]
+pub struct Handler {
+    pub exec: Option<Vec<String>>,
+    pub http_get: Option<ProbeHttpGet>,
+    pub sleep: Option<i64>
+}
+
+impl Handler {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Handler. Only one of exec, http_get or sleep could be set
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        check_single_handler(ast, &HOOK_HANDLERS)?;
+
+        Ok(Handler {
+            exec: get_value_for_t_lax::<Vec<String>>(ast, "exec"),
+            http_get: get_value_for_t_lax::<ProbeHttpGet>(ast, "http_get"),
+            sleep: get_value_for_t_lax::<i64>(ast, "sleep")
+        })
+    }
+}
+
+impl Lifecycle {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Lifecycle with the post_start & pre_stop hooks
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    pub fn new(ast: &Value) -> Result<Self, LError> {
+        Ok(Lifecycle {
+            post_start: ast.get("post_start").map(Handler::new).transpose()?,
+            pre_stop: ast.get("pre_stop").map(Handler::new).transpose()?
+        })
+    }
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use toml::Value;
 use crate::lib::helper::error::{
     LError,
@@ -13,12 +14,37 @@ pub mod toleration;
 pub mod volume;
 pub mod resource;
 pub mod probes;
+pub mod port;
+pub mod security;
+pub mod lifecycle;
+pub mod topology;
+pub mod strategy;
+
+// Constant
+// Tables of the [workload] which are not containers
+const WORKLOAD_TABLES: [&str; 5] = ["security", "node_selector", "strategy", "pod_labels", "pod_annotations"];
 
 #[derive(Debug, Clone, Default)]
 pub struct Workload {
     pub replicas: Option<i32>,
+    pub strategy: Option<strategy::Strategy>,
+    pub min_ready_seconds: Option<i32>,
+    pub revision_history_limit: Option<i32>,
+    pub progress_deadline_seconds: Option<i32>,
+    pub service_name: Option<String>,
+    pub pod_management_policy: Option<String>,
+    pub service_account: Option<String>,
+    pub pod_labels: Option<BTreeMap<String, String>>,
+    pub pod_annotations: Option<BTreeMap<String, String>>,
     pub tolerations: Option<Vec<toleration::Toleration>>,
+    pub node_selector: Option<BTreeMap<String, String>>,
+    pub topology_spread: Option<Vec<topology::TopologySpread>>,
+    pub priority_class: Option<String>,
+    pub termination_grace_period_seconds: Option<i64>,
+    pub security: Option<security::Security>,
     pub containers: Vec<Container>,
+    pub init_containers: Vec<Container>,
+    pub volumes: Option<Vec<volume::Volume>>
 }
 
 #[derive(Debug, Default, Clone)]
@@ -31,12 +57,20 @@ pub struct Image {
 #[derive(Debug, Default, Clone)]
 pub struct Container {
     pub name: String,
+    pub init: bool,
+    pub default: bool,
     pub image: Image,
+    pub command: Option<Vec<String>>,
+    pub args: Option<Vec<String>>,
+    pub working_dir: Option<String>,
+    pub ports: Option<Vec<port::Port>>,
     pub env_from: Option<env::EnvFrom>,
     pub env: Option<env::Env>,
     pub volume_mounts: Option<Vec<volume::VolumeMount>>,
     pub resources: Option<resource::Resources>,
-    pub probes: Option<probes::Probes>
+    pub probes: Option<probes::Probes>,
+    pub lifecycle: Option<lifecycle::Lifecycle>,
+    pub security: Option<security::Security>
 }
 
 impl Container {
@@ -46,6 +80,8 @@ impl Container {
     /// Create a new container by filling with the basic info
     /// - name
     /// - image
+    /// - command, args & working_dir
+    /// - ports
     ///
     /// # Arguments
     /// * `name` &str
@@ -57,14 +93,22 @@ impl Container {
         let image_repo = get_value_for_t::<String>(ast, "image")?;
         let image_tag = get_value_for_t::<String>(ast, "tag")?;
         let policy = get_value_for_t_lax::<String>(ast, "policy");
+        let init = get_value_for_t_lax::<bool>(ast, "init").unwrap_or_default();
+        let default = get_value_for_t_lax::<bool>(ast, "default").unwrap_or_default();
 
         Ok(Container {
             name: name.to_string(),
+            init,
+            default,
             image: Image {
                 repo: image_repo,
                 tag: image_tag,
                 policy
             },
+            command: get_value_for_t_lax::<Vec<String>>(ast, "command"),
+            args: get_value_for_t_lax::<Vec<String>>(ast, "args"),
+            working_dir: get_value_for_t_lax::<String>(ast, "working_dir"),
+            ports: port::get_ports(ast)?,
             ..Default::default()
         })
     }
@@ -139,11 +183,46 @@ impl Container {
     ///
     /// * `mut self` - Self
     /// * `ast` - &Value
-    fn set_probes(mut self, ast: &Value) -> Self {
+    fn set_probes(mut self, ast: &Value) -> Result<Self, LError> {
         if let Some(p) = ast.get("probes") {
-            self.probes = Some(probes::Probes::new(p));
+            self.probes = Some(probes::Probes::new(p)?);
         }
-        self
+        Ok(self)
+    }
+
+    /// Set the lifecycle hooks to Container wrapper
+    ///
+    /// # Arguments
+    ///
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    fn set_lifecycle(mut self, ast: &Value) -> Result<Self, LError> {
+        self.lifecycle = ast.get("lifecycle").map(lifecycle::Lifecycle::new).transpose()?;
+        Ok(self)
+    }
+
+    /// Set Security
+    ///
+    /// # Description
+    /// Set the security context of the container. When the pod use the restricted preset
+    /// the preset is also applied to the container
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    /// * `ast` - &Value
+    /// * `restricted` - bool
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn set_security(mut self, ast: &Value, restricted: bool) -> Result<Self, LError> {
+        let security = security::get_container_security(ast)?;
+        self.security = match (security, restricted) {
+            (Some(sec), true) => Some(sec.set_restricted()),
+            (None, true) => Some(security::Security::default().set_restricted()),
+            (sec, false) => sec
+        };
+
+        Ok(self)
     }
 }
 
@@ -160,9 +239,24 @@ impl Workload {
     /// Result<Self, LError>
     fn new(ast: &Value) -> Result<Self, LError> {
         let replicas = get_value_for_t_lax::<i32>(ast, "replicas");
+        let service_account = get_value_for_t_lax::<String>(ast, "service_account");
         Ok(Workload {
             replicas,
+            strategy: strategy::get_strategy(ast)?,
+            min_ready_seconds: get_value_for_t_lax::<i32>(ast, "min_ready_seconds"),
+            revision_history_limit: get_value_for_t_lax::<i32>(ast, "revision_history_limit"),
+            progress_deadline_seconds: get_value_for_t_lax::<i32>(ast, "progress_deadline_seconds"),
+            service_name: get_value_for_t_lax::<String>(ast, "service_name"),
+            pod_management_policy: get_value_for_t_lax::<String>(ast, "pod_management_policy"),
+            service_account,
+            pod_labels: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "pod_labels"),
+            pod_annotations: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "pod_annotations"),
             tolerations: toleration::Toleration::get_toleration_list(&ast),
+            node_selector: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "node_selector"),
+            topology_spread: topology::get_topology_spread(ast)?,
+            priority_class: get_value_for_t_lax::<String>(ast, "priority_class"),
+            termination_grace_period_seconds: get_value_for_t_lax::<i64>(ast, "termination_grace_period_seconds"),
+            security: security::get_pod_security(ast)?,
             ..Default::default()
         })
     }
@@ -170,6 +264,12 @@ impl Workload {
     /// Set Spec
     ///
     /// # Description
+    /// Set the containers of the workload. Containers which have the `init` flag are used as
+    /// init containers. Containers are kept in the declaration order of the template.
+    /// Only one container could have the `default` flag and an init container could not have it. The security, node_selector, strategy, pod_labels & pod_annotations tables are not containers
+    /// and a container could not use one of these names
+    ///
+    /// # Arguments
     /// * `mut self` - Self
     /// * `ast` - &Value
     ///
@@ -178,20 +278,43 @@ impl Workload {
     fn set_spec(mut self, ast: &Value) -> Result<Self, LError> {
         let specs = ast.as_table().ok_or_else(|| LError::from(Error::WorkloadMalformatted))?;
 
+        let restricted = self.security.as_ref().map(|s| s.restricted).unwrap_or_default();
+
         let mut containers = Vec::new();
+        let mut init_containers = Vec::new();
         for (name, items) in specs.into_iter() {
-            if items.is_table() {
+            // a reserved table which define an image is a container using a reserved name
+            if WORKLOAD_TABLES.contains(&name.as_str()) && items.get("image").is_some() {
+                return Err(LError::from(Error::ReservedContainerName(name.to_owned())));
+            }
+
+            if items.is_table() && !WORKLOAD_TABLES.contains(&name.as_str()) {
                 let container = Container::new(name, items)?
                     .set_envs(items)
                     .set_volumes_mounts(items)
                     .set_resources(items)
-                    .set_probes(items);
-
-                containers.push(container);
+                    .set_probes(items)?
+                    .set_lifecycle(items)?
+                    .set_security(items, restricted)?;
+
+                if container.init {
+                    init_containers.push(container);
+                } else {
+                    containers.push(container);
+                }
             }
         }
         
+        if let Some(container) = init_containers.iter().find(|c| c.default) {
+            return Err(LError::from(Error::DefaultInitContainer(container.name.to_owned())));
+        }
+
+        if containers.iter().filter(|c| c.default).count() > 1 {
+            return Err(LError::from(Error::MultipleDefaultContainers));
+        }
+
         self.containers = containers;
+        self.init_containers = init_containers;
 
         Ok(self)
     }
@@ -200,7 +323,7 @@ impl Workload {
 /// Get Workload
 ///
 /// # Description
-/// Retrieve a workload from the TOML template
+/// Retrieve a workload from the TOML template. The [volumes] table of the root is used as the volumes of the pod
 ///
 /// # Arguments
 /// * `ast` - &Value
@@ -211,7 +334,10 @@ pub fn get_workload(ast: &Value) -> Result<Workload, LError> {
     let workload = ast.get("workload")
         .ok_or_else(|| LError::from(Error::WorkloadNotExist))?;
 
-    Workload::new(workload)?.set_spec(workload)
+    let mut workload = Workload::new(workload)?.set_spec(workload)?;
+    workload.volumes = volume::get_volumes(ast)?;
+
+    Ok(workload)
 }
 
 #[cfg(test)]
@@ -228,6 +354,7 @@ mod test {
 
             [workload]
                 replicas = 3
+                service_account = 'rusty-sa'
 
                 [workload.rust]
                     image = 'foo'
@@ -242,6 +369,8 @@ mod test {
 
         let workload = workload.unwrap();
         assert_eq!(workload.replicas.unwrap(), 3);
+        assert_eq!(workload.service_account.unwrap(), "rusty-sa");
+        assert_eq!(workload.containers.len(), 1);
         let rust = workload.containers.get(0);
         assert!(rust.is_some());
         
@@ -500,4 +629,432 @@ mod test {
         assert_eq!(http_get.path.unwrap(), "/v3");
         assert_eq!(http_get.port.unwrap(), "4000");
     }
+
+    #[test]
+    fn expect_to_parse_init_containers_in_order() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty', tier = 'backend' }
+
+            [workload]
+                [workload.migrate]
+                    image = 'rusty-migration'
+                    tag = '1.0.0'
+                    init = true
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                [workload.fetch_config]
+                    image = 'busybox'
+                    tag = 'latest'
+                    init = true
+                    volume_mounts = [
+                        { name = 'config', mount_path = '/config' }
+                    ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+
+        assert_eq!(workload.containers.len(), 1);
+        assert_eq!(workload.containers.first().unwrap().name, "rusty");
+
+        let names = workload.init_containers
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect::<Vec<&str>>();
+
+        assert_eq!(names, vec!["migrate", "fetch_config"]);
+        assert!(workload.init_containers.last().unwrap().volume_mounts.is_some());
+    }
+
+    #[test]
+    fn expect_to_fail_multiple_default_containers() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+                    default = true
+
+                [workload.proxy]
+                    image = 'envoy'
+                    tag = '1.18.3'
+                    default = true
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_workload(&ast).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_default_init_container() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.migrate]
+                    image = 'rusty-migration'
+                    tag = '1.0.0'
+                    init = true
+                    default = true
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "The init container `migrate` could not have the default flag");
+    }
+
+    #[test]
+    fn expect_to_parse_command_and_ports() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+                    command = ['/bin/rusty']
+                    args = ['--port', '8080']
+                    working_dir = '/app'
+                    ports = [
+                        { name = 'http', container_port = 8080 },
+                        { name = 'metrics', container_port = 9090, protocol = 'TCP', host_port = 9090 }
+                    ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+        let container = workload.containers.first().unwrap();
+
+        assert_eq!(container.command.as_ref().unwrap(), &vec!["/bin/rusty"]);
+        assert_eq!(container.args.as_ref().unwrap(), &vec!["--port", "8080"]);
+        assert_eq!(container.working_dir.as_ref().unwrap(), "/app");
+
+        let ports = container.ports.as_ref().unwrap();
+        assert_eq!(ports.len(), 2);
+        assert_eq!(ports.first().unwrap().name.as_ref().unwrap(), "http");
+        assert_eq!(ports.first().unwrap().container_port, 8080);
+        assert_eq!(ports.last().unwrap().host_port.unwrap(), 9090);
+    }
+
+    #[test]
+    fn expect_to_fail_reserved_container_name() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.strategy]
+                    image = 'strategy'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "`strategy` is a reserved table of the workload and could not be used as a container name");
+    }
+
+    #[test]
+    fn expect_to_fail_missing_grpc_port() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.probes.liveness]
+                        grpc = { service = 'rusty' }
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "port is required in the grpc action of a probe");
+    }
+
+    #[test]
+    fn expect_to_fail_multiple_handlers() {
+        let probe = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.probes.liveness]
+                        exec = ['/bin/healthcheck']
+                        grpc = { port = 9090 }
+        "#;
+
+        let ast = probe.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "Only one of exec, http_get, tcp_socket, grpc could be set");
+
+        let hook = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.lifecycle]
+                        pre_stop = { exec = ['/bin/drain'], sleep = 10 }
+        "#;
+
+        let ast = hook.parse::<Value>().unwrap();
+        assert!(get_workload(&ast).is_err());
+    }
+
+    #[test]
+    fn expect_to_fail_missing_container_port() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+                    ports = [
+                        { name = 'http', protocol = 'TCP' }
+                    ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "container_port is required in the ports of a container");
+    }
+
+    #[test]
+    fn expect_to_parse_restricted_security() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.security]
+                    restricted = true
+                    fs_group = 2000
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                    [workload.rusty.security]
+                        read_only_root_filesystem = true
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+        assert_eq!(workload.containers.len(), 1);
+
+        let pod_security = workload.security.unwrap();
+        assert!(pod_security.run_as_non_root.unwrap());
+        assert_eq!(pod_security.fs_group.unwrap(), 2000);
+
+        let security = workload.containers.first().unwrap().security.to_owned().unwrap();
+        assert!(security.read_only_root_filesystem.unwrap());
+        assert!(!security.allow_privilege_escalation.unwrap());
+        assert_eq!(security.capabilities.unwrap().drop, vec!["ALL"]);
+    }
+
+    #[test]
+    fn expect_to_parse_startup_probe_and_lifecycle() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+
+                [workload.rusty.probes]
+                    [workload.rusty.probes.startup]
+                        http_get = { path = "/health", port = "http" }
+                        failure_thresold = 30
+                        period_seconds = 10
+
+                    [workload.rusty.probes.liveness]
+                        grpc = { port = 9090, service = "rusty" }
+
+                [workload.rusty.lifecycle]
+                    pre_stop = { sleep = 10 }
+                    post_start = { exec = ["/bin/warmup"] }
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+        let container = workload.containers.first().unwrap().to_owned();
+
+        let probes = container.probes.unwrap();
+        let startup = probes.startup.unwrap();
+        assert_eq!(startup.period_seconds.unwrap(), 10);
+        assert_eq!(startup.http_get.unwrap().port.unwrap(), "http");
+
+        let grpc = probes.liveness.unwrap().grpc.unwrap();
+        assert_eq!(grpc.port, 9090);
+        assert_eq!(grpc.service.unwrap(), "rusty");
+
+        let lifecycle = container.lifecycle.unwrap();
+        assert_eq!(lifecycle.pre_stop.unwrap().sleep.unwrap(), 10);
+        assert_eq!(lifecycle.post_start.unwrap().exec.unwrap(), vec!["/bin/warmup"]);
+    }
+
+    #[test]
+    fn expect_to_parse_scheduling() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                node_selector = { "kubernetes.io/os" = "linux" }
+                priority_class = 'high-priority'
+                termination_grace_period_seconds = 60
+                topology_spread = [
+                    { max_skew = 2, topology_key = 'topology.kubernetes.io/zone' },
+                    { topology_key = 'kubernetes.io/hostname', when_unsatisfiable = 'ScheduleAnyway', labels = { app = 'rusty' } }
+                ]
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+
+        assert_eq!(workload.node_selector.unwrap().get("kubernetes.io/os").unwrap(), "linux");
+        assert_eq!(workload.priority_class.unwrap(), "high-priority");
+        assert_eq!(workload.termination_grace_period_seconds.unwrap(), 60);
+
+        let spread = workload.topology_spread.unwrap();
+        let zone = spread.first().unwrap();
+        assert_eq!(zone.max_skew, 2);
+        assert_eq!(zone.when_unsatisfiable, "DoNotSchedule");
+        assert!(zone.labels.is_none());
+
+        let hostname = spread.last().unwrap();
+        assert_eq!(hostname.max_skew, 1);
+        assert_eq!(hostname.labels.as_ref().unwrap().get("app").unwrap(), "rusty");
+    }
+
+    #[test]
+    fn expect_to_fail_missing_topology_key() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                topology_spread = [
+                    { max_skew = 2 }
+                ]
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let res = get_workload(&ast);
+
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err().message, "topology_key is required in a topology spread constraint");
+    }
+
+    #[test]
+    fn expect_to_parse_strategy() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                min_ready_seconds = 10
+                revision_history_limit = 5
+                progress_deadline_seconds = 600
+
+                [workload.strategy]
+                    type = 'RollingUpdate'
+                    max_surge = '25%'
+                    max_unavailable = 0
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let workload = get_workload(&ast).unwrap();
+        assert_eq!(workload.containers.len(), 1);
+        assert_eq!(workload.min_ready_seconds.unwrap(), 10);
+        assert_eq!(workload.revision_history_limit.unwrap(), 5);
+        assert_eq!(workload.progress_deadline_seconds.unwrap(), 600);
+
+        let strategy = workload.strategy.unwrap();
+        assert_eq!(strategy.kind.unwrap(), "RollingUpdate");
+        assert_eq!(strategy.max_surge.unwrap(), crate::lib::parser::policy::Budget::Percent("25%".to_owned()));
+        assert_eq!(strategy.max_unavailable.unwrap(), crate::lib::parser::policy::Budget::Number(0));
+    }
+
+    #[test]
+    fn expect_to_fail_wrong_max_surge() {
+        let template = r#"
+            kind = 'workload::deployment'
+            name = 'rusty'
+            metadata = { name = 'rusty' }
+
+            [workload]
+                [workload.strategy]
+                    max_surge = 'many'
+
+                [workload.rusty]
+                    image = 'rusty'
+                    tag = '1.0.0'
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_workload(&ast).is_err());
+    }
 }
\ No newline at end of file
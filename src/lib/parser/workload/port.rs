@@ -0,0 +1,66 @@
+use toml::Value;
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
+use crate::lib::helper::error::{
+    LError,
+    workload::Error
+};
+
+/// Port
+///
+/// # Description
+/// Port exposed by a container. The name could be used by the services and the probes
+#[derive(Debug, Clone, Default)]
+pub struct Port {
+    pub name: Option<String>,
+    pub container_port: i32,
+    pub protocol: Option<String>,
+    pub host_port: Option<i32>
+}
+
+impl Port {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Port. The container_port is required
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let container_port = get_value_for_t::<i32>(ast, "container_port")
+            .map_err(|_| LError::from(Error::MissingContainerPort))?;
+
+        Ok(Port {
+            name: get_value_for_t_lax::<String>(ast, "name"),
+            container_port,
+            protocol: get_value_for_t_lax::<String>(ast, "protocol"),
+            host_port: get_value_for_t_lax::<i32>(ast, "host_port")
+        })
+    }
+}
+
+/// Get Ports
+///
+/// # Description
+/// Retrieve the ports of a container. It has the following toml definition
+///
+/// ports = [
+///     { name = "http", container_port = 80, protocol = "TCP" }
+/// ]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Vec<Port>>, LError>
+pub fn get_ports(ast: &Value) -> Result<Option<Vec<Port>>, LError> {
+    match ast.get("ports").and_then(|p| p.as_array()) {
+        Some(ports) => ports.iter()
+            .map(Port::new)
+            .collect::<Result<Vec<Port>, LError>>()
+            .map(Some),
+        None => Ok(None)
+    }
+}
\ No newline at end of file
@@ -1,12 +1,21 @@
 use std::collections::BTreeMap;
 use toml::Value;
-use crate::lib::helper::toml::get_value_for_t_lax;
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
 use crate::lib::helper::conv::Convert;
+use crate::lib::helper::error::{
+    LError,
+    workload::Error
+};
+
+// Constant
+// Actions of a probe. Only one of them could be set
+const PROBE_HANDLERS: [&str; 4] = ["exec", "http_get", "tcp_socket", "grpc"];
 
 #[derive(Debug, Default, Clone)]
 pub struct Probes {
     pub liveness: Option<Probe>,
-    pub readiness: Option<Probe>
+    pub readiness: Option<Probe>,
+    pub startup: Option<Probe>
 }
 
 #[derive(Debug, Default, Clone)]
@@ -14,7 +23,9 @@ pub struct Probe {
     pub exec: Option<Vec<String>>,
     pub failure_thresold: Option<i32>,
     pub http_get: Option<ProbeHttpGet>,
+    pub grpc: Option<ProbeGrpc>,
     pub initial_delays_seconds: Option<i32>,
+    pub period_seconds: Option<i32>,
     pub success_thresold: Option<i32>,
     pub tcp_socket: Option<BTreeMap<String, String>>,
     pub termination_grace_period_seconds: Option<i32>,
@@ -30,23 +41,33 @@ pub struct ProbeHttpGet {
     pub scheme: Option<String>
 }
 
+#[derive(Debug, Default, Clone)]
+pub struct ProbeGrpc {
+    pub port: i32,
+    pub service: Option<String>
+}
+
 impl Probes {
     /// Create a Probes struct
     ///
     /// # Arguments
     ///
     /// * `ast` - &Value
-    pub fn new(ast: &Value) -> Self {
+    pub fn new(ast: &Value) -> Result<Self, LError> {
         let mut probes = Probes::default();
         if let Some(liveness) = ast.get("liveness") {
-            probes.liveness = Some(Probe::new(liveness));
+            probes.liveness = Some(Probe::new(liveness)?);
         }
 
         if let Some(readiness) = ast.get("readiness") {
-            probes.readiness = Some(Probe::new(readiness));
+            probes.readiness = Some(Probe::new(readiness)?);
         }
 
-        probes
+        if let Some(startup) = ast.get("startup") {
+            probes.startup = Some(Probe::new(startup)?);
+        }
+
+        Ok(probes)
     }
 }
 
@@ -56,26 +77,39 @@ impl Probe {
     /// # Arguments
     ///
     /// * `ast` - &Value
-    pub fn new(ast: &Value) -> Self {
+    pub fn new(ast: &Value) -> Result<Self, LError> {
+        check_single_handler(ast, &PROBE_HANDLERS)?;
+
         let exec = get_value_for_t_lax::<Vec<String>>(ast, "exec");
         let http_get = get_value_for_t_lax::<ProbeHttpGet>(ast, "http_get");
+        let grpc = ast.get("grpc").map(ProbeGrpc::new).transpose()?;
         let failure = get_value_for_t_lax::<i32>(ast, "failure_thresold");
         let delay = get_value_for_t_lax::<i32>(ast, "initial_delay_seconds");
+        let period = get_value_for_t_lax::<i32>(ast, "period_seconds");
         let success = get_value_for_t_lax::<i32>(ast, "success_thresold");
-        let socket = get_value_for_t_lax::<BTreeMap<String, String>>(ast, "tcp_socket");
+        let socket = ast.get("tcp_socket").map(|socket| {
+            let mut map = BTreeMap::convert(socket);
+            if let Some(port) = get_port(socket) {
+                map.insert("port".to_owned(), port);
+            }
+
+            map
+        });
         let termination = get_value_for_t_lax::<i32>(ast, "termination_grace_period_seconds");
         let timeout = get_value_for_t_lax::<i32>(ast, "timeout_seconds");
 
-        Probe {
+        Ok(Probe {
             exec,
             failure_thresold: failure,
             http_get,
+            grpc,
             initial_delays_seconds: delay,
+            period_seconds: period,
             success_thresold: success,
             tcp_socket: socket,
             termination_grace_period_seconds: termination,
             timeout_seconds: timeout
-        }
+        })
     }
 }
 
@@ -83,7 +117,7 @@ impl Convert for ProbeHttpGet {
     fn convert(v: &Value) -> Self {
         let host = get_value_for_t_lax::<String>(v, "host");
         let path = get_value_for_t_lax::<String>(v, "path");
-        let port = get_value_for_t_lax::<String>(v, "port");
+        let port = get_port(v);
         let scheme = get_value_for_t_lax::<String>(v, "scheme");
         let headers = get_value_for_t_lax::<BTreeMap<String, String>>(v, "http_headers");
 
@@ -95,4 +129,61 @@ impl Convert for ProbeHttpGet {
             scheme
         }
     }
+}
+
+impl ProbeGrpc {
+    /// Create a new grpc action. The port is required
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - &Value
+    fn new(v: &Value) -> Result<Self, LError> {
+        let port = get_value_for_t::<i32>(v, "port")
+            .map_err(|_| LError::from(Error::MissingGrpcPort))?;
+
+        Ok(ProbeGrpc {
+            port,
+            service: get_value_for_t_lax::<String>(v, "service")
+        })
+    }
+}
+
+/// Get Port
+///
+/// # Description
+/// Retrieve the port of a probe. The port could be a number or the name of a port of the container
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Option<String>
+pub fn get_port(ast: &Value) -> Option<String> {
+    match ast.get("port")? {
+        Value::Integer(port) => Some(port.to_string()),
+        port => port.as_str().map(|p| p.to_owned())
+    }
+}
+
+/// Check Single Handler
+///
+/// # Description
+/// Check that only one of the handlers of a probe or a lifecycle hook is set
+///
+/// # Arguments
+/// * `ast` - &Value
+/// * `handlers` - &[&str]
+///
+/// # Return
+/// Result<(), LError>
+pub fn check_single_handler(ast: &Value, handlers: &[&str]) -> Result<(), LError> {
+    let set = handlers.iter()
+        .filter(|handler| ast.get(*handler).is_some())
+        .count();
+
+    if set > 1 {
+        return Err(LError::from(Error::MultipleHandlers(handlers.join(", "))));
+    }
+
+    Ok(())
 }
\ No newline at end of file
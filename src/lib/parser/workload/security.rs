@@ -0,0 +1,259 @@
+use toml::Value;
+use crate::lib::helper::toml::get_value_for_t_lax;
+use crate::lib::helper::conv::Convert;
+use crate::lib::helper::error::{
+    LError,
+    workload::Error
+};
+
+// Constant
+const RUNTIME_DEFAULT: &str = "RuntimeDefault";
+const DROP_ALL: &str = "ALL";
+// Fields of the security context which only exist on a container
+const CONTAINER_FIELDS: [&str; 4] = ["read_only_root_filesystem", "allow_privilege_escalation", "privileged", "capabilities"];
+// Fields of the security context which only exist on a pod
+const POD_FIELDS: [&str; 2] = ["fs_group", "supplemental_groups"];
+
+/// Security
+///
+/// # Description
+/// Security context of a pod or a container. The fields which only exist on a container
+/// such as read_only_root_filesystem could not be set on the pod and the other way around
+#[derive(Debug, Clone, Default)]
+pub struct Security {
+    pub restricted: bool,
+    pub run_as_user: Option<i64>,
+    pub run_as_group: Option<i64>,
+    pub run_as_non_root: Option<bool>,
+    pub fs_group: Option<i64>,
+    pub supplemental_groups: Option<Vec<i64>>,
+    pub read_only_root_filesystem: Option<bool>,
+    pub allow_privilege_escalation: Option<bool>,
+    pub privileged: Option<bool>,
+    pub capabilities: Option<Capabilities>,
+    pub seccomp_profile: Option<SeccompProfile>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Capabilities {
+    pub add: Vec<String>,
+    pub drop: Vec<String>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SeccompProfile {
+    pub kind: String,
+    pub localhost_profile: Option<String>
+}
+
+impl Convert for Capabilities {
+    fn convert(ast: &Value) -> Self {
+        Capabilities {
+            add: get_value_for_t_lax::<Vec<String>>(ast, "add").unwrap_or_default(),
+            drop: get_value_for_t_lax::<Vec<String>>(ast, "drop").unwrap_or_default()
+        }
+    }
+}
+
+impl Convert for SeccompProfile {
+    fn convert(ast: &Value) -> Self {
+        if let Some(kind) = ast.as_str() {
+            return SeccompProfile {
+                kind: kind.to_owned(),
+                localhost_profile: None
+            };
+        }
+
+        SeccompProfile {
+            kind: get_value_for_t_lax::<String>(ast, "type").unwrap_or_default(),
+            localhost_profile: get_value_for_t_lax::<String>(ast, "localhost_profile")
+        }
+    }
+}
+
+impl Convert for Security {
+    fn convert(ast: &Value) -> Self {
+        let security = Security {
+            restricted: get_value_for_t_lax::<bool>(ast, "restricted").unwrap_or_default(),
+            run_as_user: get_value_for_t_lax::<i64>(ast, "run_as_user"),
+            run_as_group: get_value_for_t_lax::<i64>(ast, "run_as_group"),
+            run_as_non_root: get_value_for_t_lax::<bool>(ast, "run_as_non_root"),
+            fs_group: get_value_for_t_lax::<i64>(ast, "fs_group"),
+            supplemental_groups: get_value_for_t_lax::<Vec<i64>>(ast, "supplemental_groups"),
+            read_only_root_filesystem: get_value_for_t_lax::<bool>(ast, "read_only_root_filesystem"),
+            allow_privilege_escalation: get_value_for_t_lax::<bool>(ast, "allow_privilege_escalation"),
+            privileged: get_value_for_t_lax::<bool>(ast, "privileged"),
+            capabilities: ast.get("capabilities").map(Capabilities::convert),
+            seccomp_profile: ast.get("seccomp_profile").map(SeccompProfile::convert)
+        };
+
+        if security.restricted {
+            return security.set_restricted();
+        }
+
+        security
+    }
+}
+
+impl Security {
+    /// Set Restricted
+    ///
+    /// # Description
+    /// Fill the fields which are not set with the values required by the restricted Pod Security Standard
+    /// - run_as_non_root = true
+    /// - allow_privilege_escalation = false
+    /// - capabilities = { drop = ["ALL"] }
+    /// - seccomp_profile = "RuntimeDefault"
+    ///
+    /// # Arguments
+    /// * `mut self` - Self
+    ///
+    /// # Return
+    /// Self
+    pub fn set_restricted(mut self) -> Self {
+        self.restricted = true;
+        self.run_as_non_root = self.run_as_non_root.or(Some(true));
+        self.allow_privilege_escalation = self.allow_privilege_escalation.or(Some(false));
+
+        if self.capabilities.is_none() {
+            self.capabilities = Some(Capabilities {
+                add: Vec::new(),
+                drop: vec![DROP_ALL.to_owned()]
+            });
+        }
+
+        if self.seccomp_profile.is_none() {
+            self.seccomp_profile = Some(SeccompProfile {
+                kind: RUNTIME_DEFAULT.to_owned(),
+                localhost_profile: None
+            });
+        }
+
+        self
+    }
+}
+
+/// Get Pod Security
+///
+/// # Description
+/// Retrieve the security context of the pod. The fields which only exist on a container return an error
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Security>, LError>
+pub fn get_pod_security(ast: &Value) -> Result<Option<Security>, LError> {
+    let security = match ast.get("security") {
+        Some(security) => security,
+        None => return Ok(None)
+    };
+
+    if let Some(field) = CONTAINER_FIELDS.iter().find(|field| security.get(*field).is_some()) {
+        return Err(LError::from(Error::ContainerSecurityField(field.to_string())));
+    }
+
+    Ok(Some(Security::convert(security)))
+}
+
+/// Get Container Security
+///
+/// # Description
+/// Retrieve the security context of a container. The fields which only exist on a pod return an error
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Security>, LError>
+pub fn get_container_security(ast: &Value) -> Result<Option<Security>, LError> {
+    let security = match ast.get("security") {
+        Some(security) => security,
+        None => return Ok(None)
+    };
+
+    if let Some(field) = POD_FIELDS.iter().find(|field| security.get(*field).is_some()) {
+        return Err(LError::from(Error::PodSecurityField(field.to_string())));
+    }
+
+    Ok(Some(Security::convert(security)))
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_security() {
+        let template = r#"
+        run_as_user = 1000
+        fs_group = 2000
+        read_only_root_filesystem = true
+        capabilities = { add = ["NET_BIND_SERVICE"], drop = ["ALL"] }
+        seccomp_profile = { type = "Localhost", localhost_profile = "profiles/rusty.json" }
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let security = Security::convert(&ast);
+
+        assert!(!security.restricted);
+        assert_eq!(security.run_as_user.unwrap(), 1000);
+        assert_eq!(security.fs_group.unwrap(), 2000);
+        assert!(security.read_only_root_filesystem.unwrap());
+        assert!(security.run_as_non_root.is_none());
+        assert_eq!(security.capabilities.unwrap().add, vec!["NET_BIND_SERVICE"]);
+
+        let seccomp = security.seccomp_profile.unwrap();
+        assert_eq!(seccomp.kind, "Localhost");
+        assert_eq!(seccomp.localhost_profile.unwrap(), "profiles/rusty.json");
+    }
+
+    #[test]
+    fn expect_to_fill_restricted_preset() {
+        let template = r#"
+        restricted = true
+        run_as_user = 1000
+        seccomp_profile = "Unconfined"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let security = Security::convert(&ast);
+
+        assert!(security.run_as_non_root.unwrap());
+        assert!(!security.allow_privilege_escalation.unwrap());
+        assert_eq!(security.capabilities.unwrap().drop, vec!["ALL"]);
+        // explicit values are kept
+        assert_eq!(security.run_as_user.unwrap(), 1000);
+        assert_eq!(security.seccomp_profile.unwrap().kind, "Unconfined");
+    }
+
+    #[test]
+    fn expect_to_fail_container_field_on_pod() {
+        let template = r#"
+        [security]
+            run_as_user = 1000
+            privileged = true
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let security = get_pod_security(&ast);
+
+        assert!(security.is_err());
+        assert_eq!(security.unwrap_err().message, "`privileged` is only supported in the security context of a container");
+    }
+
+    #[test]
+    fn expect_to_fail_pod_field_on_container() {
+        let template = r#"
+        [security]
+            fs_group = 2000
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let security = get_container_security(&ast);
+
+        assert!(security.is_err());
+        assert_eq!(security.unwrap_err().message, "`fs_group` is only supported in the security context of the pod");
+    }
+}
\ No newline at end of file
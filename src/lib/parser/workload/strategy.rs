@@ -0,0 +1,48 @@
+use toml::Value;
+use crate::lib::helper::error::LError;
+use crate::lib::helper::toml::get_value_for_t_lax;
+use crate::lib::parser::policy::{Budget, get_budget};
+
+/// Strategy
+///
+/// # Description
+/// Update strategy of a workload. The fields which are used depends on the kind of workload
+///     - deployment: type, max_surge, max_unavailable
+///     - statefulset: type, partition
+///     - daemonset: type, max_unavailable
+#[derive(Debug, Clone, Default)]
+pub struct Strategy {
+    pub kind: Option<String>,
+    pub max_surge: Option<Budget>,
+    pub max_unavailable: Option<Budget>,
+    pub partition: Option<i32>
+}
+
+/// Get Strategy
+///
+/// # Description
+/// Retrieve the update strategy of a workload. It has the following toml definition
+///
+/// [workload.strategy]
+///     type = "RollingUpdate"
+///     max_surge = "25%"
+///     max_unavailable = 1
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Strategy>, LError>
+pub fn get_strategy(ast: &Value) -> Result<Option<Strategy>, LError> {
+    let strategy = match ast.get("strategy") {
+        Some(s) => s,
+        None => return Ok(None)
+    };
+
+    Ok(Some(Strategy {
+        kind: get_value_for_t_lax::<String>(strategy, "type"),
+        max_surge: get_budget(strategy, "max_surge")?,
+        max_unavailable: get_budget(strategy, "max_unavailable")?,
+        partition: get_value_for_t_lax::<i32>(strategy, "partition")
+    }))
+}
\ No newline at end of file
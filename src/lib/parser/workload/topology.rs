@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use toml::Value;
+use crate::lib::helper::toml::{get_value_for_t, get_value_for_t_lax};
+use crate::lib::helper::error::{
+    LError,
+    workload::Error
+};
+
+/// TopologySpread
+///
+/// # Description
+/// Topology spread constraint of the pods. When the labels are not set the selector of the template is used
+#[derive(Debug, Clone, Default)]
+pub struct TopologySpread {
+    pub max_skew: i32,
+    pub topology_key: String,
+    pub when_unsatisfiable: String,
+    pub labels: Option<BTreeMap<String, String>>
+}
+
+impl TopologySpread {
+    /// New
+    ///
+    /// # Description
+    /// Create a new TopologySpread. The topology_key is required
+    ///
+    /// # Arguments
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(ast: &Value) -> Result<Self, LError> {
+        let topology_key = get_value_for_t::<String>(ast, "topology_key")
+            .map_err(|_| LError::from(Error::MissingTopologyKey))?;
+
+        Ok(TopologySpread {
+            max_skew: get_value_for_t_lax::<i32>(ast, "max_skew").unwrap_or(1),
+            topology_key,
+            when_unsatisfiable: get_value_for_t_lax::<String>(ast, "when_unsatisfiable")
+                .unwrap_or_else(|| "DoNotSchedule".to_owned()),
+            labels: get_value_for_t_lax::<BTreeMap<String, String>>(ast, "labels")
+        })
+    }
+}
+
+/// Get Topology Spread
+///
+/// # Description
+/// Retrieve the topology spread constraints of the workload. It has the following toml definition
+///
+/// topology_spread = [
+///     { max_skew = 1, topology_key = "topology.kubernetes.io/zone", when_unsatisfiable = "DoNotSchedule" }
+/// ]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Vec<TopologySpread>>, LError>
+pub fn get_topology_spread(ast: &Value) -> Result<Option<Vec<TopologySpread>>, LError> {
+    match ast.get("topology_spread").and_then(|t| t.as_array()) {
+        Some(constraints) => constraints.iter()
+            .map(TopologySpread::new)
+            .collect::<Result<Vec<TopologySpread>, LError>>()
+            .map(Some),
+        None => Ok(None)
+    }
+}
\ No newline at end of file
@@ -1,11 +1,78 @@
 use toml::Value;
-use crate::lib::helper::toml::get_value_for_t_lax;
+use crate::lib::helper::error::{
+    LError,
+    workload::Error
+};
+use crate::lib::helper::toml::{
+    get_value_for_t,
+    get_value_for_t_lax
+};
 
 #[derive(Debug, Default, Clone)]
 pub struct VolumeMount {
     pub name: Option<String>,
     pub path: Option<String>,
-    pub read_only: Option<bool>
+    pub read_only: Option<bool>,
+    pub sub_path: Option<String>
+}
+
+/// Volume
+///
+/// # Description
+/// Volume of a pod. The volume is referenced by the volume_mounts of the containers with it's name
+#[derive(Debug, Clone)]
+pub struct Volume {
+    pub name: String,
+    pub default_mode: Option<i32>,
+    pub source: VolumeSource
+}
+
+#[derive(Debug, Clone)]
+pub enum VolumeSource {
+    ConfigMap(ObjectSource),
+    Secret(ObjectSource),
+    EmptyDir {
+        medium: Option<String>,
+        size_limit: Option<String>
+    },
+    Claim {
+        name: String,
+        read_only: Option<bool>
+    },
+    HostPath {
+        path: String,
+        kind: Option<String>
+    },
+    Projected(Vec<Projection>)
+}
+
+#[derive(Debug, Clone)]
+pub enum Projection {
+    ConfigMap(ObjectSource),
+    Secret(ObjectSource),
+    ServiceAccountToken {
+        path: String,
+        audience: Option<String>,
+        expiration_seconds: Option<i64>
+    }
+}
+
+/// ObjectSource
+///
+/// # Description
+/// ConfigMap or Secret used by a volume
+#[derive(Debug, Clone, Default)]
+pub struct ObjectSource {
+    pub name: String,
+    pub items: Vec<KeyToPath>,
+    pub optional: Option<bool>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyToPath {
+    pub key: String,
+    pub path: String,
+    pub mode: Option<i32>
 }
 
 impl VolumeMount {
@@ -23,11 +90,13 @@ impl VolumeMount {
         let name = get_value_for_t_lax::<String>(ast, "name");
         let path = get_value_for_t_lax::<String>(ast, "mount_path");
         let read_only = get_value_for_t_lax::<bool>(ast, "read_only");
+        let sub_path = get_value_for_t_lax::<String>(ast, "sub_path");
 
         VolumeMount {
             name,
             path,
-            read_only
+            read_only,
+            sub_path
         }
     }
 
@@ -39,4 +108,244 @@ impl VolumeMount {
 
         Some(v)
     }
+}
+
+impl Volume {
+    /// New
+    ///
+    /// # Description
+    /// Create a new Volume. The source of the volume is defined by one of the following key
+    /// config_map, secret, empty_dir, claim, host_path, projected
+    ///
+    /// # Arguments
+    /// * `name` - &str
+    /// * `ast` - &Value
+    ///
+    /// # Return
+    /// Result<Self, LError>
+    fn new(name: &str, ast: &Value) -> Result<Self, LError> {
+        let source = if let Some(config_map) = ast.get("config_map") {
+            VolumeSource::ConfigMap(get_object_source(config_map, ast))
+        } else if let Some(secret) = ast.get("secret") {
+            VolumeSource::Secret(get_object_source(secret, ast))
+        } else if let Some(empty_dir) = ast.get("empty_dir") {
+            VolumeSource::EmptyDir {
+                medium: get_value_for_t_lax::<String>(empty_dir, "medium"),
+                size_limit: get_value_for_t_lax::<String>(empty_dir, "size_limit")
+            }
+        } else if let Some(claim) = ast.get("claim") {
+            VolumeSource::Claim {
+                name: claim.as_str().unwrap_or_default().to_owned(),
+                read_only: get_value_for_t_lax::<bool>(ast, "read_only")
+            }
+        } else if let Some(host_path) = ast.get("host_path") {
+            VolumeSource::HostPath {
+                path: host_path.as_str().unwrap_or_default().to_owned(),
+                kind: get_value_for_t_lax::<String>(ast, "type")
+            }
+        } else if let Some(projected) = ast.get("projected").and_then(|p| p.as_array()) {
+            let projections = projected.iter()
+                .map(|p| get_projection(name, p))
+                .collect::<Result<Vec<Projection>, LError>>()?;
+
+            VolumeSource::Projected(projections)
+        } else {
+            return Err(LError::from(Error::UnknownVolumeSource(name.to_owned())));
+        };
+
+        Ok(Volume {
+            name: name.to_owned(),
+            default_mode: get_value_for_t_lax::<i32>(ast, "default_mode"),
+            source
+        })
+    }
+}
+
+/// Get Object Source
+///
+/// # Description
+/// Retrieve a ConfigMap or a Secret source. The items & optional keys are read from the table of the volume
+///
+/// # Arguments
+/// * `name` - &Value
+/// * `ast` - &Value
+///
+/// # Return
+/// ObjectSource
+fn get_object_source(name: &Value, ast: &Value) -> ObjectSource {
+    let items = ast.get("items")
+        .and_then(|items| items.as_array())
+        .map(|items| items.iter().map(|item| KeyToPath {
+            key: get_value_for_t::<String>(item, "key").unwrap_or_default(),
+            path: get_value_for_t::<String>(item, "path").unwrap_or_default(),
+            mode: get_value_for_t_lax::<i32>(item, "mode")
+        }).collect())
+        .unwrap_or_default();
+
+    ObjectSource {
+        name: name.as_str().unwrap_or_default().to_owned(),
+        items,
+        optional: get_value_for_t_lax::<bool>(ast, "optional")
+    }
+}
+
+/// Get Projection
+///
+/// # Arguments
+/// * `name` - &str
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Projection, LError>
+fn get_projection(name: &str, ast: &Value) -> Result<Projection, LError> {
+    if let Some(config_map) = ast.get("config_map") {
+        return Ok(Projection::ConfigMap(get_object_source(config_map, ast)));
+    }
+
+    if let Some(secret) = ast.get("secret") {
+        return Ok(Projection::Secret(get_object_source(secret, ast)));
+    }
+
+    if let Some(token) = ast.get("service_account_token") {
+        return Ok(Projection::ServiceAccountToken {
+            path: get_value_for_t::<String>(token, "path")?,
+            audience: get_value_for_t_lax::<String>(token, "audience"),
+            expiration_seconds: get_value_for_t_lax::<i64>(token, "expiration_seconds")
+        });
+    }
+
+    Err(LError::from(Error::UnknownVolumeSource(name.to_owned())))
+}
+
+/// Get Volumes
+///
+/// # Description
+/// Retrieve the volumes of a pod. It has the following toml definition
+///
+/// <root>
+/// [volumes]
+///     [volumes.<name>]
+///
+/// # Arguments
+/// * `ast` - &Value
+///
+/// # Return
+/// Result<Option<Vec<Volume>>, LError>
+pub fn get_volumes(ast: &Value) -> Result<Option<Vec<Volume>>, LError> {
+    let volumes = match ast.get("volumes").and_then(|v| v.as_table()) {
+        Some(v) => v,
+        None => return Ok(None)
+    };
+
+    let volumes = volumes
+        .into_iter()
+        .map(|(name, item)| Volume::new(name, item))
+        .collect::<Result<Vec<Volume>, LError>>()?;
+
+    Ok(Some(volumes))
+}
+
+#[cfg(test)]
+mod tests {
+    use toml::Value;
+    use super::*;
+
+    #[test]
+    fn expect_to_parse_volumes() {
+        let template = r#"
+        [volumes]
+            [volumes.config]
+                config_map = "nginx-config"
+                default_mode = 0o644
+                items = [
+                    { key = "nginx.conf", path = "nginx.conf" }
+                ]
+
+            [volumes.cache]
+                empty_dir = { medium = "Memory", size_limit = "1Gi" }
+
+            [volumes.data]
+                claim = "nginx-data"
+                read_only = true
+
+            [volumes.logs]
+                host_path = "/var/log"
+                type = "Directory"
+
+            [volumes.tls]
+                secret = "nginx-tls"
+                optional = true
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let volumes = get_volumes(&ast).unwrap().unwrap();
+        assert_eq!(volumes.len(), 5);
+
+        let cache = volumes.iter().find(|v| v.name == "cache").unwrap();
+        match &cache.source {
+            VolumeSource::EmptyDir { medium, size_limit } => {
+                assert_eq!(medium.as_ref().unwrap(), "Memory");
+                assert_eq!(size_limit.as_ref().unwrap(), "1Gi");
+            },
+            _ => panic!("expect an empty_dir volume")
+        }
+
+        let config = volumes.iter().find(|v| v.name == "config").unwrap();
+        assert_eq!(config.default_mode.unwrap(), 420);
+        match &config.source {
+            VolumeSource::ConfigMap(source) => {
+                assert_eq!(source.name, "nginx-config");
+                assert_eq!(source.items.first().unwrap().key, "nginx.conf");
+            },
+            _ => panic!("expect a config_map volume")
+        }
+
+        let data = volumes.iter().find(|v| v.name == "data").unwrap();
+        match &data.source {
+            VolumeSource::Claim { name, read_only } => {
+                assert_eq!(name, "nginx-data");
+                assert!(read_only.unwrap());
+            },
+            _ => panic!("expect a claim volume")
+        }
+    }
+
+    #[test]
+    fn expect_to_parse_projected_volume() {
+        let template = r#"
+        [volumes]
+            [volumes.all]
+                projected = [
+                    { config_map = "nginx-config" },
+                    { secret = "nginx-secret", items = [{ key = "password", path = "password", mode = 0o400 }] },
+                    { service_account_token = { path = "token", audience = "vault", expiration_seconds = 3600 } }
+                ]
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        let volumes = get_volumes(&ast).unwrap().unwrap();
+
+        match &volumes.first().unwrap().source {
+            VolumeSource::Projected(projections) => {
+                assert_eq!(projections.len(), 3);
+                match projections.get(1).unwrap() {
+                    Projection::Secret(source) => assert_eq!(source.items.first().unwrap().mode.unwrap(), 256),
+                    _ => panic!("expect a secret projection")
+                }
+            },
+            _ => panic!("expect a projected volume")
+        }
+    }
+
+    #[test]
+    fn expect_to_fail_unknown_volume_source() {
+        let template = r#"
+        [volumes]
+            [volumes.nfs]
+                server = "nfs.local"
+        "#;
+
+        let ast = template.parse::<Value>().unwrap();
+        assert!(get_volumes(&ast).is_err());
+    }
 }
\ No newline at end of file
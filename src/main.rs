@@ -10,15 +10,21 @@ fn main() {
     let yaml = load_yaml!("cli.yaml");
     let mut app = App::from(yaml);
     let matches = app.clone().get_matches();
+    let validate = matches!(matches.subcommand(), Some(("validate", _)));
 
     let res = match matches.subcommand() {
         Some(("generate", args)) => cli::generate::run(args),
         Some(("diff", args)) => cli::diff::run(args),
         Some(("verify", args)) => cli::dry::run(args),
+        Some(("validate", args)) => cli::validate::run(args),
         _ => app.print_help().map_err(CError::from)
     };
 
     if let Err(err) = res {
         println!("{}❌ Something went wrong: {}", color::Fg(color::Red), err.message);
+        // the validate command fail with a non zero exit code so that it could be used in a CI
+        if validate {
+            std::process::exit(1);
+        }
     }
 }
@@ -128,5 +128,109 @@ fn expect_to_run_verify_create_dry() -> Result<(), Box<dyn Error>> {
 
     cmd.assert().success();
 
+    Ok(())
+}
+
+#[test]
+fn expect_to_run_verify_with_concurrency() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+
+    cmd
+        .arg("verify")
+        .arg("-p")
+        .arg("examples/diff")
+        .arg("--concurrency")
+        .arg("2");
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn expect_to_validate_offline() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+
+    cmd
+        .arg("validate")
+        .arg("-p")
+        .arg("examples")
+        .arg("--offline");
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn expect_validate_to_fail_without_offline() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+
+    cmd
+        .arg("validate")
+        .arg("-p")
+        .arg("examples");
+
+    cmd.assert().failure();
+
+    Ok(())
+}
+
+#[test]
+fn expect_to_validate_custom_crd_offline() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+
+    cmd
+        .arg("validate")
+        .arg("-p")
+        .arg("examples/crd")
+        .arg("--offline")
+        .arg("--crd-dir")
+        .arg("examples/crd/schemas");
+
+    cmd.assert().success();
+
+    Ok(())
+}
+
+#[test]
+fn expect_to_generate_argo_nested_steps() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+    let output = std::env::temp_dir().join(format!("maomao-argo-golden-{}", std::process::id()));
+
+    cmd
+        .arg("generate")
+        .arg("-p")
+        .arg("examples/crd")
+        .arg("-o")
+        .arg(&output);
+    cmd.assert().success();
+
+    let generated = std::fs::read_to_string(output.join("argo-workflow.yaml"))?;
+    let golden = std::fs::read_to_string("tests/golden/argo-workflow.yaml")?;
+    std::fs::remove_dir_all(&output)?;
+    assert_eq!(generated, golden);
+
+    Ok(())
+}
+
+#[test]
+fn expect_to_merge_namespace_first() -> Result<(), Box<dyn Error>> {
+    let mut cmd = Command::cargo_bin("maomao")?;
+
+    cmd
+        .arg("generate")
+        .arg("-p")
+        .arg("examples")
+        .arg("-m")
+        .arg("-q");
+
+    let output = cmd.output()?;
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let first_document = stdout.split("---").find(|doc| !doc.trim().is_empty()).unwrap_or_default();
+    assert!(first_document.contains("kind: Namespace"));
+
     Ok(())
 }
\ No newline at end of file